@@ -0,0 +1,38 @@
+//! Wrappers implementing `Deref` through `deref_cfg!` arms gated on the features of this crate
+//!
+//! Build it with no features, `--features fast`, `--features wide` or both; every combination
+//! selects exactly one arm per type.
+
+use deref::deref_cfg;
+
+/// Counter stored at several widths, deref'ing to the one matching the enabled features
+pub struct Counter {
+    pub narrow: u16,
+    pub fast: u32,
+    pub wide: u64,
+    pub both: u128,
+}
+
+deref_cfg! {
+    #[cfg(not(any(feature = "fast", feature = "wide")))]
+    Counter => u16 @ narrow,
+    #[cfg(all(feature = "fast", not(feature = "wide")))]
+    Counter => u32 @ fast,
+    #[cfg(all(feature = "wide", not(feature = "fast")))]
+    Counter => u64 @ wide,
+    #[cfg(all(feature = "fast", feature = "wide"))]
+    Counter => u128 @ both,
+}
+
+/// Tuple struct deref'ing to a map with `fast` and to a list of pairs otherwise
+pub struct Table(
+    pub std::collections::HashMap<u8, &'static str>,
+    pub Vec<(u8, &'static str)>,
+);
+
+deref_cfg! {
+    #[cfg(feature = "fast")]
+    Table => std::collections::HashMap<u8, &'static str> @ 0,
+    #[cfg(not(feature = "fast"))]
+    Table => Vec<(u8, &'static str)> @ 1,
+}
@@ -0,0 +1,61 @@
+use std::any::type_name;
+use std::ops::Deref;
+
+use deref_cfg_features::{Counter, Table};
+
+fn target_of<T: Deref>(_: &T) -> &'static str {
+    type_name::<T::Target>()
+}
+
+fn counter() -> Counter {
+    Counter {
+        narrow: 1,
+        fast: 2,
+        wide: 3,
+        both: 4,
+    }
+}
+
+fn table() -> Table {
+    Table([(1, "map")].into(), vec![(1, "list")])
+}
+
+#[test]
+#[cfg(not(any(feature = "fast", feature = "wide")))]
+fn default_counter_target() {
+    assert_eq!(target_of(&counter()), type_name::<u16>());
+    assert_eq!(*counter(), 1);
+}
+
+#[test]
+#[cfg(all(feature = "fast", not(feature = "wide")))]
+fn fast_counter_target() {
+    assert_eq!(target_of(&counter()), type_name::<u32>());
+    assert_eq!(*counter(), 2);
+}
+
+#[test]
+#[cfg(all(feature = "wide", not(feature = "fast")))]
+fn wide_counter_target() {
+    assert_eq!(target_of(&counter()), type_name::<u64>());
+    assert_eq!(*counter(), 3);
+}
+
+#[test]
+#[cfg(all(feature = "fast", feature = "wide"))]
+fn combined_counter_target() {
+    assert_eq!(target_of(&counter()), type_name::<u128>());
+    assert_eq!(*counter(), 4);
+}
+
+#[test]
+#[cfg(feature = "fast")]
+fn fast_table_target() {
+    assert_eq!(table().get(&1), Some(&"map"));
+}
+
+#[test]
+#[cfg(not(feature = "fast"))]
+fn default_table_target() {
+    assert_eq!(table()[0], (1, "list"));
+}
@@ -0,0 +1,51 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DataStruct, DeriveInput};
+
+use crate::deref::{DerefOptions, construct_from_field, find_deref_field};
+
+/// Implementation function for the `ArbitraryFromDeref` derive
+pub fn impl_arbitrary_from_deref(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let Data::Struct(DataStruct { fields, .. }) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "ArbitraryFromDeref can only be used on structs",
+        ));
+    };
+
+    let mut options = DerefOptions::default();
+    options.parse_attrs(&input.attrs)?;
+    let deref_field = find_deref_field(&input.ident, fields, &mut options)?;
+    let field_ty = &deref_field.ty;
+    let construct = construct_from_field(fields, &deref_field, quote!(value));
+
+    // The input lifetime of `Arbitrary` joins the struct's own generics on the impl only
+    let mut generics = input.generics.clone();
+    generics.params.insert(0, syn::parse_quote!('arbitrary));
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_ty: ::arbitrary::Arbitrary<'arbitrary>));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::arbitrary::Arbitrary<'arbitrary> for #name #ty_generics #where_clause {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'arbitrary>) -> ::arbitrary::Result<Self> {
+                let value = <#field_ty as ::arbitrary::Arbitrary<'arbitrary>>::arbitrary(u)?;
+                ::core::result::Result::Ok(#construct)
+            }
+
+            fn arbitrary_take_rest(u: ::arbitrary::Unstructured<'arbitrary>) -> ::arbitrary::Result<Self> {
+                let value = <#field_ty as ::arbitrary::Arbitrary<'arbitrary>>::arbitrary_take_rest(u)?;
+                ::core::result::Result::Ok(#construct)
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, ::core::option::Option<usize>) {
+                <#field_ty as ::arbitrary::Arbitrary<'arbitrary>>::size_hint(depth)
+            }
+        }
+    })
+}
@@ -0,0 +1,121 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DataStruct, DeriveInput};
+
+use crate::deref::{DerefOptions, MarkedField, find_deref_field};
+
+/// Marker of the `AsRef` derive, taking precedence over `#[deref]` when present
+const AS_REF_ATTR: &str = "as_ref";
+
+/// Implementation function for the `AsRef` forwarding derive
+pub fn impl_as_ref_trait(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let deref_field = match as_ref_field(input)? {
+        Some(marked) => marked,
+        None => conversion_field(input, "AsRef")?,
+    };
+    Ok(forward_reference(
+        input,
+        &deref_field,
+        quote!(::core::convert::AsRef),
+        "as_ref",
+        false,
+    ))
+}
+
+/// Implementation function for the `AsMut` forwarding derive
+pub fn impl_as_mut_trait(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let deref_field = conversion_field(input, "AsMut")?;
+    Ok(forward_reference(
+        input,
+        &deref_field,
+        quote!(::core::convert::AsMut),
+        "as_mut",
+        true,
+    ))
+}
+
+/// Implementation function for the `Borrow` and `BorrowMut` forwarding derives
+///
+/// `BorrowMut` requires `Borrow`, so the mutable derive implements both.
+pub fn impl_borrow_trait(input: &DeriveInput, is_mut: bool) -> syn::Result<TokenStream2> {
+    let deref_field = conversion_field(input, if is_mut { "BorrowMut" } else { "Borrow" })?;
+    let borrow = forward_reference(input, &deref_field, quote!(::core::borrow::Borrow), "borrow", false);
+    let borrow_mut = is_mut.then(|| {
+        forward_reference(
+            input,
+            &deref_field,
+            quote!(::core::borrow::BorrowMut),
+            "borrow_mut",
+            true,
+        )
+    });
+
+    Ok(quote! {
+        #borrow
+        #borrow_mut
+    })
+}
+
+/// Build `impl Trait<FieldType> for Name` whose method borrows the field
+fn forward_reference(
+    input: &DeriveInput,
+    deref_field: &MarkedField,
+    trait_path: TokenStream2,
+    method: &str,
+    is_mut: bool,
+) -> TokenStream2 {
+    let name = &input.ident;
+    let member = &deref_field.member;
+    let field_ty = &deref_field.ty;
+    let method = format_ident!("{}", method);
+    let mutability = is_mut.then(|| quote!(mut));
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #trait_path<#field_ty> for #name #ty_generics #where_clause {
+            #[inline]
+            fn #method(&#mutability self) -> &#mutability #field_ty {
+                &#mutability self.#member
+            }
+        }
+    }
+}
+
+/// Function to find the field marked with `#[as_ref]`, if any
+fn as_ref_field(input: &DeriveInput) -> syn::Result<Option<MarkedField>> {
+    let Data::Struct(DataStruct { fields, .. }) = &input.data else {
+        return Ok(None);
+    };
+
+    let mut marked = None;
+    for (index, field) in fields.iter().enumerate() {
+        let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident(AS_REF_ATTR)) else {
+            continue;
+        };
+        attr.meta.require_path_only()?;
+
+        if marked.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "Only one field can be marked with #[as_ref]",
+            ));
+        }
+        marked = Some(MarkedField::new(index, field));
+    }
+
+    Ok(marked)
+}
+
+/// Function to find the field a conversion derive forwards to, selected like for `Deref`
+fn conversion_field(input: &DeriveInput, trait_name: &str) -> syn::Result<MarkedField> {
+    let Data::Struct(DataStruct { fields, .. }) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            format!("{} can only be used on structs", trait_name),
+        ));
+    };
+
+    let mut options = DerefOptions::default();
+    options.parse_attrs(&input.attrs)?;
+    find_deref_field(&input.ident, fields, &mut options)
+}
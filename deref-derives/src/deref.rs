@@ -1,133 +1,1731 @@
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::{Attribute, Data, DataStruct, DeriveInput, Fields, Index, Type};
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::parse::Parse;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, GenericArgument, GenericParam, Index, LitStr,
+    PathArguments, Type,
+};
+
+/// Attribute that marks the deref field and carries its options
+const DEREF_ATTR: &str = "deref";
+
+/// Marker attribute of the `DerefMut` derive, accepted as an alias of a bare `#[deref]`
+const DEREF_MUT_ATTR: &str = "deref_mut";
+
+/// Legacy marker attribute, accepted as an alias of a bare `#[deref]`
+const AUTO_REF_ATTR: &str = "auto_ref";
 
 /// Unified implementation function for Deref and DerefMut traits
 pub fn impl_deref_trait(input: &DeriveInput, is_mut: bool) -> syn::Result<TokenStream2> {
-    let name = &input.ident;
-    let generics = &input.generics;
-    let attr_name = "auto_ref"; // 统一使用 auto_ref 属性
     let trait_name = if is_mut { "DerefMut" } else { "Deref" };
 
-    // Get struct fields
-    let fields = match &input.data {
-        Data::Struct(DataStruct { fields, .. }) => fields,
-        _ => {
+    // Get struct fields, or the fields of the first variant of an enum
+    let (fields, variants) = match &input.data {
+        Data::Struct(DataStruct { fields, .. }) => (fields, Vec::new()),
+        Data::Enum(data) => {
+            let Some(first) = data.variants.first() else {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    format!("{} cannot be used on enums without variants", trait_name),
+                ));
+            };
+            if data.variants.len() > 1 {
+                check_homogeneous_variants(data, trait_name)?;
+            }
+            (&first.fields, data.variants.iter().collect())
+        }
+        Data::Union(_) => {
             return Err(syn::Error::new_spanned(
                 input,
-                format!("{} can only be used on structs", trait_name),
+                format!("{} can only be used on structs and enums", trait_name),
             ));
         }
     };
 
-    // Find the field marked with #[auto_ref]
-    let (deref_field, field_type) = find_deref_field(fields, attr_name)?;
+    // Container-level options come first, the marked field may add more
+    let mut options = DerefOptions::default();
+    options.parse_attrs(&input.attrs)?;
+
+    if let [variant] = variants.as_slice() {
+        options.parse_attrs(&variant.attrs)?;
+    }
+
+    if let Some(item) = &options.static_item {
+        return impl_static_deref(input, item, &options, is_mut);
+    }
+
+    if !options.feature_targets.is_empty() {
+        return impl_feature_targets(input, is_mut, fields, &variants, options);
+    }
+
+    expand_deref(input, is_mut, fields, &variants, options)
+}
+
+/// Expand once per `#[deref(feature = "...", target = "...")]`, each gated on its feature, plus
+/// the default target when none of the features is enabled
+///
+/// Cargo features must be additive, so enabling several keyed features is allowed: the last one
+/// listed wins.
+fn impl_feature_targets(
+    input: &DeriveInput,
+    is_mut: bool,
+    fields: &Fields,
+    variants: &[&syn::Variant],
+    mut options: DerefOptions,
+) -> syn::Result<TokenStream2> {
+    let feature_targets = std::mem::take(&mut options.feature_targets);
+    let features = feature_targets.iter().map(|(feature, _)| feature).collect::<Vec<_>>();
+    let mut expanded = Vec::new();
+
+    // A feature's impls are left out when a feature listed after it is enabled too
+    for (index, (feature, target)) in feature_targets.iter().enumerate() {
+        let later = &features[index + 1..];
+        let mut keyed = options.clone();
+        keyed.target = Some(target.clone());
+        keyed.cfg = Some(match &options.cfg {
+            Some(cfg) => syn::parse_quote!(all(#cfg, feature = #feature, not(any(#(feature = #later),*)))),
+            None => syn::parse_quote!(all(feature = #feature, not(any(#(feature = #later),*)))),
+        });
+        expanded.push(expand_deref(input, is_mut, fields, variants, keyed)?);
+    }
+
+    let mut default = options.clone();
+    default.cfg = Some(match &options.cfg {
+        Some(cfg) => syn::parse_quote!(all(#cfg, not(any(#(feature = #features),*)))),
+        None => syn::parse_quote!(not(any(#(feature = #features),*))),
+    });
+    expanded.push(expand_deref(input, is_mut, fields, variants, default)?);
+
+    Ok(quote!(#(#expanded)*))
+}
+
+/// Generate the impls for one configuration of the options
+fn expand_deref(
+    input: &DeriveInput,
+    is_mut: bool,
+    fields: &Fields,
+    variants: &[&syn::Variant],
+    mut options: DerefOptions,
+) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let generics = &input.generics;
+
+    // Find the field marked with #[deref] or an alias, or the sole field
+    let mut deref_field = find_field_path(name, fields, &mut options)?;
+
+    // A projection continues the path below the deref field
+    if let Some(project) = &options.project {
+        deref_field
+            .path
+            .extend(parse_field_path(project, project.value().split('.'))?);
+    }
+
+    // The types of nested fields are not visible here, so the last one is named by `target`
+    let nested = match (&options.project, &options.field) {
+        (Some(project), _) => Some(("project", project)),
+        (None, Some(field)) if !deref_field.path.is_empty() => Some(("field", field)),
+        _ => None,
+    };
+    if let Some((key, path)) = nested {
+        let target = options.target.as_ref().ok_or_else(|| {
+            syn::Error::new_spanned(
+                path,
+                format!(
+                    "a nested #[deref({} = \"...\")] path requires #[deref(target = \"...\")], the type of its last field",
+                    key
+                ),
+            )
+        })?;
+        if let Some(option) = [
+            ("constructor", options.constructor.is_some()),
+            ("validate", options.validate.is_some()),
+            ("clone_field_only", options.clone_field_only),
+            ("from_ref", options.from_ref),
+        ]
+        .into_iter()
+        .find_map(|(option, set)| set.then_some(option))
+        {
+            return Err(syn::Error::new_spanned(
+                path,
+                format!(
+                    "#[deref({})] cannot be combined with a nested #[deref({})] path",
+                    option, key
+                ),
+            ));
+        }
+        deref_field.ty = target.clone();
+    }
+    deref_field.variants = match variants {
+        [variant] => vec![(variant.ident.clone(), deref_field.member.clone())],
+        variants => variants
+            .iter()
+            .map(|variant| {
+                (
+                    variant.ident.clone(),
+                    DerefField::new(0, variant.fields.iter().next().unwrap()),
+                )
+            })
+            .collect(),
+    };
+
+    // Building a value needs a single variant to build
+    if variants.len() > 1
+        && let Some(option) = [
+            ("constructor", options.constructor.is_some()),
+            ("validate", options.validate.is_some()),
+            ("clone_field_only", options.clone_field_only),
+        ]
+        .into_iter()
+        .find_map(|(option, set)| set.then_some(option))
+    {
+        return Err(syn::Error::new_spanned(
+            name,
+            format!("#[deref({})] is not supported on enums with several variants", option),
+        ));
+    }
+
+    if let Some(lifetime) = &options.lifetime
+        && !generics.lifetimes().any(|param| param.lifetime == *lifetime)
+    {
+        return Err(syn::Error::new_spanned(
+            lifetime,
+            format!("lifetime `{}` is not declared on `{}`", lifetime, name),
+        ));
+    }
+
+    // Lifetimes of an explicit target must come from the struct, the impl declares no others
+    if let Some(target) = &options.target
+        && let Some(lifetime) = free_lifetimes(target)
+            .into_iter()
+            .find(|lifetime| !generics.lifetimes().any(|param| param.lifetime == *lifetime))
+    {
+        return Err(syn::Error::new_spanned(
+            target,
+            format!(
+                "lifetime `{}` in #[deref(target)] is not declared on `{}`",
+                lifetime, name
+            ),
+        ));
+    }
+
+    if is_mut && options.through {
+        reject_shared_pointer(&deref_field.ty)?;
+    }
+
+    let DerefAccess {
+        target,
+        deref_expr,
+        deref_mut_expr,
+    } = deref_field.access(&options)?;
+
+    // Extra predicates requested with `#[deref(bound = "...")]` join the struct's own where clause
+    let mut generics = generics.clone();
+    if !options.bound.is_empty() {
+        generics.make_where_clause().predicates.extend(options.bound.clone());
+    }
 
     // Generate implementation code
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    // Panics of `unwrap` are reported at the dereferencing call site
+    let track_caller = options.unwrap.then(|| quote!(#[track_caller]));
+    let trace_deref = options
+        .trace
+        .map(|backend| trace_access(backend, &format!("{}::deref", name)));
+    let trace_deref_mut = options
+        .trace
+        .map(|backend| trace_access(backend, &format!("{}::deref_mut", name)));
+
+    // Spanned on the target, so a private field type leaking from a public type is reported there
+    let target_item = quote_spanned!(target.span()=> type Target = #target;);
+
     // Basic Deref implementation
     let deref_impl = quote! {
-        impl #impl_generics std::ops::Deref for #name #ty_generics #where_clause {
-            type Target = #field_type;
+        impl #impl_generics ::core::ops::Deref for #name #ty_generics #where_clause {
+            #target_item
 
             #[inline]
+            #track_caller
             fn deref(&self) -> &Self::Target {
-                &self.#deref_field
+                #trace_deref
+                #deref_expr
             }
         }
     };
 
+    let mut items = vec![deref_impl];
+
+    if let Some(explicit) = &options.target
+        && options.via.is_none()
+        && let Some(note) = target_mismatch_note(&deref_field.ty, explicit)
+    {
+        items.push(note);
+    }
+
     // If DerefMut, also need to implement DerefMut trait
     if is_mut {
-        let deref_mut_impl = quote! {
-            impl #impl_generics std::ops::DerefMut for #name #ty_generics #where_clause {
+        items.push(quote! {
+            impl #impl_generics ::core::ops::DerefMut for #name #ty_generics #where_clause {
                 #[inline]
+                #track_caller
                 fn deref_mut(&mut self) -> &mut Self::Target {
-                    &mut self.#deref_field
+                    #trace_deref_mut
+                    #deref_mut_expr
                 }
             }
-        };
+        });
+    }
+
+    // Inherent accessors: `#[must_use]` is not propagated by the deref operator, and `Deref::deref`
+    // cannot be called in const contexts
+    if options.must_use_accessor || options.const_accessor {
+        if options.const_accessor
+            && let Some(mode) = [
+                ("as_slice", options.as_slice),
+                ("through", options.through),
+                ("via", options.via.is_some()),
+                ("target", options.target.is_some()),
+            ]
+            .into_iter()
+            .find_map(|(mode, set)| set.then_some(mode))
+        {
+            return Err(syn::Error::new_spanned(
+                &deref_field.ty,
+                format!("#[deref(const)] cannot be combined with #[deref({})]", mode),
+            ));
+        }
+
+        let vis = &input.vis;
+        let must_use = options.must_use_accessor.then(|| quote!(#[must_use]));
+        let constness = options.const_accessor.then(|| quote!(const));
+        let docs = &deref_field.docs;
+        let get_mut = is_mut.then(|| {
+            quote! {
+                #(#docs)*
+                #must_use
+                #[inline]
+                #track_caller
+                #vis #constness fn get_mut(&mut self) -> &mut #target {
+                    #deref_mut_expr
+                }
+            }
+        });
 
-        Ok(quote! {
-            #deref_impl
-            #deref_mut_impl
+        items.push(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #(#docs)*
+                #must_use
+                #[inline]
+                #track_caller
+                #vis #constness fn get(&self) -> &#target {
+                    #deref_expr
+                }
+
+                #get_mut
+            }
+        });
+    }
+
+    // Inner-value management, only offered alongside DerefMut
+    for (option, method) in [
+        ("replace_method", &options.replace_method),
+        ("swap_method", &options.swap_method),
+        ("with_mut_method", &options.with_mut_method),
+        ("modify_method", &options.modify_method),
+    ] {
+        if let Some(method) = method
+            && !is_mut
+        {
+            return Err(syn::Error::new_spanned(
+                method,
+                format!("#[deref({})] requires #[derive(DerefMut)]", option),
+            ));
+        }
+    }
+
+    if options.replace_method.is_some()
+        || options.swap_method.is_some()
+        || options.with_mut_method.is_some()
+        || options.modify_method.is_some()
+    {
+        let vis = &input.vis;
+        let field_ty = &deref_field.ty;
+        let replace_body = deref_field.project(|place| quote!(::core::mem::replace(&mut #place, value)));
+        let swap_body = deref_field.project(|place| quote!(::core::mem::swap(&mut #place, other)));
+        let replace = options.replace_method.as_ref().map(|method| {
+            quote! {
+                #[inline]
+                #vis fn #method(&mut self, value: #field_ty) -> #field_ty {
+                    #replace_body
+                }
+            }
+        });
+        let swap = options.swap_method.as_ref().map(|method| {
+            quote! {
+                #[inline]
+                #vis fn #method(&mut self, other: &mut #field_ty) {
+                    #swap_body
+                }
+            }
+        });
+
+        // `__R` cannot collide with the type's own generic parameters
+        let with_mut = options.with_mut_method.as_ref().map(|method| {
+            quote! {
+                #[inline]
+                #vis fn #method<__R>(&mut self, f: impl FnOnce(&mut #target) -> __R) -> __R {
+                    f(#deref_mut_expr)
+                }
+            }
+        });
+
+        // Consuming, so mutations can be chained while building a value
+        let modify = options.modify_method.as_ref().map(|method| {
+            quote! {
+                #[inline]
+                #vis fn #method(mut self, f: impl FnOnce(&mut #target)) -> Self {
+                    f(#deref_mut_expr);
+                    self
+                }
+            }
+        });
+
+        items.push(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #replace
+                #swap
+                #with_mut
+                #modify
+            }
+        });
+    }
+
+    // Explicit access to the field itself, whatever the deref target
+    if let Some(api) = &options.inner_api {
+        let vis = &input.vis;
+        let field_ty = &deref_field.ty;
+        let InnerApi {
+            as_inner,
+            as_inner_mut,
+            into_inner,
+        } = api;
+        let as_inner_body = deref_field.project(|place| quote!(&#place));
+        let into_inner_body = deref_field.take();
+        let as_inner_mut = is_mut.then(|| {
+            let body = deref_field.project(|place| quote!(&mut #place));
+            quote! {
+                #[inline]
+                #vis fn #as_inner_mut(&mut self) -> &mut #field_ty {
+                    #body
+                }
+            }
+        });
+
+        items.push(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #[inline]
+                #vis fn #as_inner(&self) -> &#field_ty {
+                    #as_inner_body
+                }
+
+                #as_inner_mut
+
+                #[inline]
+                #vis fn #into_inner(self) -> #field_ty {
+                    #into_inner_body
+                }
+            }
+        });
+    }
+
+    // Tuple-style `Debug` of the deref field alone, bounded on the field's own `Debug`
+    if options.debug_wrapped {
+        let mut generics = generics.clone();
+        let field_ty = &deref_field.ty;
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#field_ty: ::core::fmt::Debug));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let type_name = name.to_string();
+        let body = deref_field.project(|place| quote!(f.debug_tuple(#type_name).field(&#place).finish()));
+
+        items.push(quote! {
+            impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #body
+                }
+            }
+        });
+    }
+
+    // Bridge from a borrowed newtype to its owned counterpart, converted from the field's own `Owned`
+    if let Some(owned) = &options.owned {
+        let value = deref_field.project(|place| quote!(::std::borrow::ToOwned::to_owned(&#place)));
+
+        items.push(quote! {
+            impl #impl_generics ::std::borrow::ToOwned for #name #ty_generics #where_clause {
+                type Owned = #owned;
+
+                #[inline]
+                fn to_owned(&self) -> Self::Owned {
+                    ::core::convert::From::from(#value)
+                }
+            }
+        });
+    }
+
+    // Reference conversions from the field type, sound thanks to `#[repr(transparent)]`
+    if options.from_ref {
+        if !is_repr_transparent(&input.attrs) {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!("#[deref(from_ref)] requires `{}` to be #[repr(transparent)]", name),
+            ));
+        }
+
+        // The layout is only the deref field's when every other field is zero-sized
+        if let Some((_, field)) = fields
+            .iter()
+            .enumerate()
+            .find(|(index, field)| DerefField::new(*index, field) != deref_field.member && !is_phantom_data(&field.ty))
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "#[deref(from_ref)] requires the fields of `{}` other than the deref field to be `PhantomData`",
+                    name
+                ),
+            ));
+        }
+
+        if let Some(validator) = &options.validate {
+            return Err(syn::Error::new_spanned(
+                validator,
+                "#[deref(from_ref)] cannot be combined with #[deref(validate)], the conversion would skip the validator",
+            ));
+        }
+
+        let vis = &input.vis;
+        let field_ty = &deref_field.ty;
+        let from_mut = is_mut.then(|| {
+            quote! {
+                #[inline]
+                #vis fn from_mut(inner: &mut #field_ty) -> &mut Self {
+                    // SAFETY: `#[repr(transparent)]` and `PhantomData` siblings give `Self` the field layout
+                    unsafe { &mut *(inner as *mut #field_ty as *mut Self) }
+                }
+            }
+        });
+
+        items.push(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #[inline]
+                #vis fn from_ref(inner: &#field_ty) -> &Self {
+                    // SAFETY: `#[repr(transparent)]` and `PhantomData` siblings give `Self` the field layout
+                    unsafe { &*(inner as *const #field_ty as *const Self) }
+                }
+
+                #from_mut
+            }
+        });
+    }
+
+    // Constructor from the field type, the other fields start from `Default`
+    if let Some(constructor) = &options.constructor {
+        let vis = options.constructor_vis.as_ref().unwrap_or(&input.vis);
+        let field_ty = &deref_field.ty;
+        let construct = construct_from_field(fields, &deref_field, quote!(value));
+
+        items.push(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #[inline]
+                #vis fn #constructor(value: #field_ty) -> Self {
+                    #construct
+                }
+            }
+        });
+    } else if let Some(vis) = &options.constructor_vis {
+        return Err(syn::Error::new_spanned(
+            vis,
+            "#[deref(constructor_vis)] is only used together with #[deref(constructor = \"...\")]",
+        ));
+    }
+
+    // Validated construction from the field type
+    if let Some(validator) = &options.validate {
+        let error = options.error.as_ref().ok_or_else(|| {
+            syn::Error::new_spanned(validator, "#[deref(validate)] requires #[deref(error = \"...\")]")
+        })?;
+        let field_ty = &deref_field.ty;
+        let construct = construct_from_field(fields, &deref_field, quote!(value));
+
+        items.push(quote! {
+            impl #impl_generics ::core::convert::TryFrom<#field_ty> for #name #ty_generics #where_clause {
+                type Error = #error;
+
+                fn try_from(value: #field_ty) -> ::core::result::Result<Self, Self::Error> {
+                    #validator(&value)?;
+                    ::core::result::Result::Ok(#construct)
+                }
+            }
+        });
+    } else if let Some(error) = &options.error {
+        return Err(syn::Error::new_spanned(
+            error,
+            "#[deref(error)] is only used together with #[deref(validate = \"...\")]",
+        ));
+    }
+
+    // Clone of the deref field alone, the other fields start over from `Default`
+    if options.clone_field_only {
+        let mut generics = generics.clone();
+        let predicates = &mut generics.make_where_clause().predicates;
+        for (index, field) in fields.iter().enumerate() {
+            let ty = &field.ty;
+            predicates.push(if DerefField::new(index, field) == deref_field.member {
+                syn::parse_quote!(#ty: ::core::clone::Clone)
+            } else {
+                syn::parse_quote!(#ty: ::core::default::Default)
+            });
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let value = deref_field.project(|place| quote!(::core::clone::Clone::clone(&#place)));
+        let construct = construct_from_field(fields, &deref_field, value);
+
+        items.push(quote! {
+            impl #impl_generics ::core::clone::Clone for #name #ty_generics #where_clause {
+                #[inline]
+                fn clone(&self) -> Self {
+                    #construct
+                }
+            }
+        });
+    }
+
+    gate_items(name, &options, items)
+}
+
+/// `Deref` of a unit struct to a `static` item, whose type is given by `target`
+fn impl_static_deref(
+    input: &DeriveInput,
+    item: &syn::Path,
+    options: &DerefOptions,
+    is_mut: bool,
+) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !matches!(
+        &input.data,
+        Data::Struct(DataStruct {
+            fields: Fields::Unit,
+            ..
         })
-    } else {
-        Ok(deref_impl)
+    ) {
+        return Err(syn::Error::new_spanned(
+            item,
+            "#[deref(static = \"...\")] is only supported on unit structs",
+        ));
     }
+    if is_mut {
+        return Err(syn::Error::new_spanned(
+            item,
+            "#[deref(static = \"...\")] cannot be used with DerefMut, a static is only borrowed immutably",
+        ));
+    }
+    let target = options.target.as_ref().ok_or_else(|| {
+        syn::Error::new_spanned(
+            item,
+            "#[deref(static = \"...\")] requires #[deref(target = \"...\")], the type of the static",
+        )
+    })?;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let trace_deref = options
+        .trace
+        .map(|backend| trace_access(backend, &format!("{}::deref", name)));
+    // Spanned on the path, so a missing or mistyped static is reported on the attribute
+    let value = quote_spanned!(item.span()=> &#item);
+
+    let deref_impl = quote! {
+        impl #impl_generics ::core::ops::Deref for #name #ty_generics #where_clause {
+            type Target = #target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                #trace_deref
+                #value
+            }
+        }
+    };
+
+    gate_items(name, options, vec![deref_impl])
 }
 
-/// Function to find the field marked with the specified attribute
-fn find_deref_field(fields: &Fields, attr_name: &str) -> syn::Result<(DerefField, Type)> {
-    match fields {
-        Fields::Named(fields_named) => {
-            let mut deref_field = None;
-            let mut field_type = None;
+/// Gate every generated item on the requested configuration
+fn gate_items(name: &syn::Ident, options: &DerefOptions, items: Vec<TokenStream2>) -> syn::Result<TokenStream2> {
+    let gate = options.cfg.as_ref().map(|cfg| {
+        let doc_cfg = options.doc_cfg.then(|| quote!(#[cfg_attr(docsrs, doc(cfg(#cfg)))]));
+        quote! {
+            #[cfg(#cfg)]
+            #doc_cfg
+        }
+    });
+    if options.doc_cfg && gate.is_none() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "#[deref(doc_cfg)] requires #[deref(cfg(...))]",
+        ));
+    }
 
-            for field in &fields_named.named {
-                if has_attribute(&field.attrs, attr_name) {
-                    if deref_field.is_some() {
-                        return Err(syn::Error::new_spanned(
-                            field,
-                            format!("Only one field can be marked with #[{}]", attr_name),
-                        ));
+    let items = items.into_iter().map(|item| quote!(#gate #item));
+    Ok(quote!(#(#items)*))
+}
+
+/// Statement logging an access in debug builds
+///
+/// The backend is chosen per use rather than by a feature of this crate, which would apply to
+/// every crate of the build once enabled anywhere.
+fn trace_access(backend: TraceBackend, message: &str) -> TokenStream2 {
+    match backend {
+        TraceBackend::Stderr => quote! {
+            #[cfg(debug_assertions)]
+            ::std::eprintln!("{}", #message);
+        },
+        TraceBackend::Log => quote! {
+            #[cfg(debug_assertions)]
+            ::log::trace!("{}", #message);
+        },
+    }
+}
+
+/// Warning for a `target` the field obviously cannot deref-coerce to, such as `u32` for a `String`
+///
+/// Derive macros cannot emit notes on stable Rust, so the note is attached to the use of a
+/// deprecated constant. No note is emitted when the types might coerce.
+fn target_mismatch_note(field_ty: &Type, target: &Type) -> Option<TokenStream2> {
+    let field = quote!(#field_ty).to_string();
+    let explicit = quote!(#target).to_string();
+    if field == explicit {
+        return None;
+    }
+
+    // Other names may be aliases of the field type, as in `type Id = u32;`, so only known types are compared
+    if !is_known_type(field_ty) || !is_known_type(target) {
+        return None;
+    }
+
+    // Scalars have no `Deref` impl, and `String`/`Vec` only deref to `str`/slices
+    let mismatched = is_scalar(field_ty)
+        || (is_scalar(target) && ["String", "Vec"].iter().any(|name| last_segment_is(field_ty, name)));
+    if !mismatched {
+        return None;
+    }
+
+    let message = format!(
+        "#[deref(target = \"{}\")] differs from the field type `{}`, which does not deref to it; \
+         use #[deref(through)] to deref to the field's own target or #[deref(via = \"as_ref\")] to convert",
+        explicit.replace(' ', ""),
+        field.replace(' ', ""),
+    );
+    Some(quote_spanned! {target.span()=>
+        const _: () = {
+            #[deprecated(note = #message)]
+            const TARGET_MISMATCH: () = ();
+            TARGET_MISMATCH
+        };
+    })
+}
+
+/// Named lifetimes of a type other than `'static` and `'_`, leaving out those bound by `for<...>`
+fn free_lifetimes(ty: &Type) -> Vec<syn::Lifetime> {
+    let mut lifetimes = Vec::new();
+    collect_type_lifetimes(ty, &mut Vec::new(), &mut lifetimes);
+    lifetimes
+}
+
+fn collect_type_lifetimes(ty: &Type, bound: &mut Vec<syn::Lifetime>, out: &mut Vec<syn::Lifetime>) {
+    match ty {
+        Type::Reference(reference) => {
+            if let Some(lifetime) = &reference.lifetime {
+                push_free_lifetime(lifetime, bound, out);
+            }
+            collect_type_lifetimes(&reference.elem, bound, out);
+        }
+        Type::Path(type_path) => {
+            if let Some(qself) = &type_path.qself {
+                collect_type_lifetimes(&qself.ty, bound, out);
+            }
+            collect_path_lifetimes(&type_path.path, bound, out);
+        }
+        Type::BareFn(bare_fn) => {
+            let depth = bound.len();
+            if let Some(binder) = &bare_fn.lifetimes {
+                bound.extend(binder.lifetimes.iter().filter_map(bound_lifetime));
+            }
+            for input in &bare_fn.inputs {
+                collect_type_lifetimes(&input.ty, bound, out);
+            }
+            if let syn::ReturnType::Type(_, output) = &bare_fn.output {
+                collect_type_lifetimes(output, bound, out);
+            }
+            bound.truncate(depth);
+        }
+        Type::TraitObject(object) => collect_bounds_lifetimes(&object.bounds, bound, out),
+        Type::ImplTrait(impl_trait) => collect_bounds_lifetimes(&impl_trait.bounds, bound, out),
+        Type::Array(array) => collect_type_lifetimes(&array.elem, bound, out),
+        Type::Slice(slice) => collect_type_lifetimes(&slice.elem, bound, out),
+        Type::Ptr(ptr) => collect_type_lifetimes(&ptr.elem, bound, out),
+        Type::Paren(paren) => collect_type_lifetimes(&paren.elem, bound, out),
+        Type::Group(group) => collect_type_lifetimes(&group.elem, bound, out),
+        Type::Tuple(tuple) => {
+            for elem in &tuple.elems {
+                collect_type_lifetimes(elem, bound, out);
+            }
+        }
+        // The tokens of a type macro are not parsed, so every lifetime in them counts
+        Type::Macro(type_macro) => collect_token_lifetimes(type_macro.mac.tokens.clone(), bound, out),
+        _ => {}
+    }
+}
+
+fn collect_path_lifetimes(path: &syn::Path, bound: &mut Vec<syn::Lifetime>, out: &mut Vec<syn::Lifetime>) {
+    for segment in &path.segments {
+        match &segment.arguments {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(args) => {
+                for arg in &args.args {
+                    match arg {
+                        GenericArgument::Lifetime(lifetime) => push_free_lifetime(lifetime, bound, out),
+                        GenericArgument::Type(ty) => collect_type_lifetimes(ty, bound, out),
+                        GenericArgument::AssocType(assoc) => collect_type_lifetimes(&assoc.ty, bound, out),
+                        GenericArgument::Constraint(constraint) => {
+                            collect_bounds_lifetimes(&constraint.bounds, bound, out)
+                        }
+                        _ => {}
                     }
+                }
+            }
+            PathArguments::Parenthesized(args) => {
+                for input in &args.inputs {
+                    collect_type_lifetimes(input, bound, out);
+                }
+                if let syn::ReturnType::Type(_, output) = &args.output {
+                    collect_type_lifetimes(output, bound, out);
+                }
+            }
+        }
+    }
+}
+
+fn collect_bounds_lifetimes<'a>(
+    bounds: impl IntoIterator<Item = &'a syn::TypeParamBound>,
+    bound: &mut Vec<syn::Lifetime>,
+    out: &mut Vec<syn::Lifetime>,
+) {
+    for param_bound in bounds {
+        match param_bound {
+            syn::TypeParamBound::Trait(trait_bound) => {
+                let depth = bound.len();
+                if let Some(binder) = &trait_bound.lifetimes {
+                    bound.extend(binder.lifetimes.iter().filter_map(bound_lifetime));
+                }
+                collect_path_lifetimes(&trait_bound.path, bound, out);
+                bound.truncate(depth);
+            }
+            syn::TypeParamBound::Lifetime(lifetime) => push_free_lifetime(lifetime, bound, out),
+            _ => {}
+        }
+    }
+}
+
+fn collect_token_lifetimes(tokens: TokenStream2, bound: &[syn::Lifetime], out: &mut Vec<syn::Lifetime>) {
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Group(group) => collect_token_lifetimes(group.stream(), bound, out),
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+                    let lifetime = syn::Lifetime {
+                        apostrophe: punct.span(),
+                        ident: ident.clone(),
+                    };
+                    push_free_lifetime(&lifetime, bound, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
 
-                    let ident = field
-                        .ident
-                        .clone()
-                        .ok_or_else(|| syn::Error::new_spanned(field, "Field must have a name"))?;
+/// Lifetime introduced by a `for<...>` binder
+fn bound_lifetime(param: &GenericParam) -> Option<syn::Lifetime> {
+    match param {
+        GenericParam::Lifetime(param) => Some(param.lifetime.clone()),
+        _ => None,
+    }
+}
+
+fn push_free_lifetime(lifetime: &syn::Lifetime, bound: &[syn::Lifetime], out: &mut Vec<syn::Lifetime>) {
+    if lifetime.ident != "static" && lifetime.ident != "_" && !bound.contains(lifetime) {
+        out.push(lifetime.clone());
+    }
+}
+
+/// Function to check if a type is a primitive scalar such as `u32` or `bool`
+fn is_scalar(ty: &Type) -> bool {
+    const SCALARS: &[&str] = &[
+        "bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f32",
+        "f64",
+    ];
+
+    matches!(ty, Type::Path(type_path) if type_path.qself.is_none()
+        && type_path.path.get_ident().is_some_and(|ident| SCALARS.iter().any(|scalar| ident == scalar)))
+}
+
+/// Function to check if a type cannot be a user alias: scalars, `str`, `String`, `Vec` and
+/// slice, array and reference types
+fn is_known_type(ty: &Type) -> bool {
+    match ty {
+        Type::Slice(_) | Type::Array(_) | Type::Reference(_) => true,
+        Type::Path(type_path) if type_path.path.is_ident("str") => true,
+        _ => is_scalar(ty) || ["String", "Vec"].iter().any(|name| last_segment_is(ty, name)),
+    }
+}
+
+/// Function to check if the last path segment of a type has the given name
+fn last_segment_is(ty: &Type, name: &str) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.segments.last().is_some_and(|segment| segment.ident == name))
+}
+
+/// Check that every variant of the enum holds exactly one field, all of the same type
+fn check_homogeneous_variants(data: &DataEnum, trait_name: &str) -> syn::Result<()> {
+    let mut expected: Option<(&syn::Ident, &Type)> = None;
+
+    for variant in &data.variants {
+        let [field] = variant.fields.iter().collect::<Vec<_>>()[..] else {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!(
+                    "{} on enums with several variants requires each variant to hold exactly one field",
+                    trait_name
+                ),
+            ));
+        };
+
+        let field_ty = &field.ty;
+        match expected {
+            None => expected = Some((&variant.ident, field_ty)),
+            Some((first, ty)) if quote!(#ty).to_string() != quote!(#field_ty).to_string() => {
+                return Err(syn::Error::new_spanned(
+                    field_ty,
+                    format!(
+                        "variant `{}` holds `{}` but `{}` holds `{}`; {} requires every variant to hold the same type",
+                        variant.ident,
+                        quote!(#field_ty),
+                        first,
+                        quote!(#ty),
+                        trait_name
+                    ),
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Build `Self` from a value for the deref field, filling the other fields with `Default::default()`
+pub(crate) fn construct_from_field(fields: &Fields, deref_field: &MarkedField, value: TokenStream2) -> TokenStream2 {
+    let member = &deref_field.member;
+    let others = fields.iter().enumerate().filter_map(|(index, field)| {
+        let other = DerefField::new(index, field);
+        // Spanned on the field type, so a missing `Default` impl is reported on that field
+        (other != *member).then(|| quote_spanned!(field.ty.span()=> #other: ::core::default::Default::default()))
+    });
+    let path = deref_field.path();
+
+    quote!(#path { #member: #value, #(#others),* })
+}
+
+/// Function to find the field marked with `#[deref]` or an alias, merging its options into `options`
+///
+/// Used by the derives taking the field type as is, which cannot follow a nested field path.
+pub(crate) fn find_deref_field(
+    name: &syn::Ident,
+    fields: &Fields,
+    options: &mut DerefOptions,
+) -> syn::Result<MarkedField> {
+    let marked = find_field_path(name, fields, options)?;
+    if let (Some(name), [_, ..]) = (&options.field, marked.path.as_slice()) {
+        return Err(syn::Error::new_spanned(
+            name,
+            "nested #[deref(field)] paths are only supported by the Deref and DerefMut derives",
+        ));
+    }
+    if let Some(project) = &options.project {
+        return Err(syn::Error::new_spanned(
+            project,
+            "#[deref(project)] is only supported by the Deref and DerefMut derives",
+        ));
+    }
+
+    Ok(marked)
+}
+
+/// Function to find the deref field, possibly the start of a nested `#[deref(field = "a.b")]` path
+fn find_field_path(name: &syn::Ident, fields: &Fields, options: &mut DerefOptions) -> syn::Result<MarkedField> {
+    if let Some(name) = options.field.clone() {
+        return select_named_field(fields, &name);
+    }
+
+    let mut marked: Option<MarkedField> = None;
+    let mut marked_attrs: &[Attribute] = &[];
+
+    for (index, field) in fields.iter().enumerate() {
+        if !is_marked(&field.attrs) {
+            continue;
+        }
+
+        // `DerefMut::Target` is `Deref::Target`, so both markers must sit on the same field
+        if marked.is_some()
+            && let Some(attr) = mismatched_deref_mut(marked_attrs, &field.attrs)
+        {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "#[deref_mut] must mark the same field as #[deref], since `DerefMut` shares the `Deref` target",
+            ));
+        }
 
-                    deref_field = Some(DerefField::Named(ident));
-                    field_type = Some(field.ty.clone());
+        if let Some(first) = &marked {
+            // Markers duplicated by other macros are ignored on request, keeping the first field,
+            // as long as they do not carry options of their own that would be dropped
+            if options.allow_duplicate_marker {
+                if marker_options(&field.attrs) == marker_options(marked_attrs) {
+                    continue;
                 }
+
+                return Err(syn::Error::new_spanned(
+                    field,
+                    format!(
+                        "#[deref(allow_duplicate_marker)] only ignores markers repeating the one on `{}`, but this marker has different options",
+                        first.member.to_token_stream()
+                    ),
+                ));
             }
 
-            match (deref_field, field_type) {
-                (Some(field), Some(ty)) => Ok((field, ty)),
+            return Err(syn::Error::new_spanned(
+                field,
+                "Only one field can be marked with #[deref]",
+            ));
+        }
+
+        let feature_targets = options.feature_targets.len();
+        options.parse_attrs(&field.attrs)?;
+        if let Some(name) = &options.field {
+            return Err(syn::Error::new_spanned(
+                name,
+                "#[deref(field = \"...\")] is only allowed on the struct",
+            ));
+        }
+        if let Some((feature, _)) = options.feature_targets.get(feature_targets) {
+            return Err(syn::Error::new_spanned(
+                feature,
+                "#[deref(feature = \"...\")] is only allowed on the struct",
+            ));
+        }
+
+        marked = Some(MarkedField::new(index, field));
+        marked_attrs = &field.attrs;
+    }
+
+    // A single field is the deref target without a marker, not counting the zero-sized
+    // `PhantomData` fields a `#[repr(transparent)]` struct may add next to it
+    if marked.is_none() {
+        let skip_phantom = options.repr_transparent && fields.len() > 1;
+        let mut candidates = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !(skip_phantom && is_phantom_data(&field.ty)));
+        if let (Some((index, field)), None) = (candidates.next(), candidates.next()) {
+            return Ok(MarkedField::new(index, field));
+        }
+    }
+
+    match fields {
+        Fields::Unit => Err(syn::Error::new_spanned(
+            name,
+            format!(
+                "Unit structs are not supported: Deref needs a field to target, as in `struct {0}(Inner);` or `struct {0} {{ inner: Inner }}`; without one, implement the trait manually",
+                name
+            ),
+        )),
+        _ => marked.ok_or_else(|| unmarked_fields_error(name, fields)),
+    }
+}
+
+/// Error for fields without a marker, naming the candidates if there are any
+fn unmarked_fields_error(name: &syn::Ident, fields: &Fields) -> syn::Error {
+    let candidates = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let member = DerefField::new(index, field);
+            format!("`{}`", quote!(#member))
+        })
+        .collect::<Vec<_>>();
+    if candidates.is_empty() {
+        return syn::Error::new_spanned(
+            fields,
+            format!(
+                "Must have one field marked with #[deref], but `{}` has no fields; add one to deref to or implement the trait manually",
+                name
+            ),
+        );
+    }
+
+    syn::Error::new_spanned(
+        fields,
+        format!(
+            "Must have one field marked with #[deref], but none of {} is; add #[deref] to one of them or select one with #[deref(field = \"...\")] on the struct",
+            candidates.join(", ")
+        ),
+    )
+}
+
+/// Function to find the field selected by a container-level `#[deref(field = "...")]`
+///
+/// Keyword names such as `"type"` match the raw identifier field `r#type`, and tuple fields are
+/// selected by index (`"0"`). A dotted path such as `"state.inner"` selects its first segment
+/// among `fields` and keeps the rest, whose fields the derive cannot see, for the compiler to check.
+fn select_named_field(fields: &Fields, name: &LitStr) -> syn::Result<MarkedField> {
+    let value = name.value();
+    let mut segments = value.split('.');
+    let first = segments.next().unwrap_or_default();
+    let first = first.strip_prefix("r#").unwrap_or(first);
+
+    if let Some(field) = fields.iter().find(|field| is_marked(&field.attrs)) {
+        return Err(syn::Error::new_spanned(
+            field,
+            "A field cannot be marked with #[deref] when #[deref(field = \"...\")] selects one",
+        ));
+    }
+
+    let path = parse_field_path(name, segments)?;
+
+    for (index, field) in fields.iter().enumerate() {
+        let matches = match &field.ident {
+            Some(ident) => ident.unraw() == first,
+            None => first.parse() == Ok(index),
+        };
+
+        if matches {
+            let mut marked = MarkedField::new(index, field);
+            marked.path = path;
+            return Ok(marked);
+        }
+    }
+
+    Err(syn::Error::new_spanned(name, format!("No field named `{}`", first)))
+}
+
+/// Parse the dotted segments of a field path into members spanned on its literal
+///
+/// Only field names and tuple indices are accepted, keeping the path a place expression.
+fn parse_field_path<'a>(literal: &LitStr, segments: impl Iterator<Item = &'a str>) -> syn::Result<Vec<syn::Member>> {
+    segments
+        .map(|segment| {
+            if let Ok(index) = segment.parse() {
+                Ok(syn::Member::Unnamed(Index {
+                    index,
+                    span: literal.span(),
+                }))
+            } else {
+                // Spanned on the attribute, so a missing nested field is reported there
+                let mut ident = syn::parse_str::<syn::Ident>(segment).map_err(|_| {
+                    syn::Error::new_spanned(
+                        literal,
+                        format!(
+                            "Invalid field path `{}`, expected field names or tuple indices separated by `.`",
+                            literal.value()
+                        ),
+                    )
+                })?;
+                ident.set_span(literal.span());
+                Ok(syn::Member::Named(ident))
+            }
+        })
+        .collect()
+}
+
+/// The field selected as the deref target
+pub(crate) struct MarkedField {
+    pub(crate) member: DerefField,
+    pub(crate) ty: Type,
+    /// The variants of an enum, each with the member holding the field
+    variants: Vec<(syn::Ident, DerefField)>,
+    /// Fields below the selected one, from a dotted `#[deref(field = "a.b")]` path
+    path: Vec<syn::Member>,
+    /// Doc comments of the field, repeated on the generated accessors
+    docs: Vec<Attribute>,
+}
+
+impl MarkedField {
+    pub(crate) fn new(index: usize, field: &Field) -> Self {
+        MarkedField {
+            member: DerefField::new(index, field),
+            ty: field.ty.clone(),
+            variants: Vec::new(),
+            path: Vec::new(),
+            docs: field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc"))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Path constructing the type, `Self` or `Self::Variant`
+    fn path(&self) -> TokenStream2 {
+        match self.variants.first() {
+            Some((variant, _)) => quote!(Self::#variant),
+            None => quote!(Self),
+        }
+    }
+
+    /// Build an expression from the place of the field inside `self`
+    ///
+    /// Struct fields are reached as `self.field`; enum fields are bound by matching every variant.
+    fn project(&self, body: impl FnOnce(TokenStream2) -> TokenStream2) -> TokenStream2 {
+        let path = &self.path;
+        if self.variants.is_empty() {
+            let member = &self.member;
+            return body(quote!(self.#member #(.#path)*));
+        }
+
+        let body = body(quote!((*field) #(.#path)*));
+        let arms = self
+            .variants
+            .iter()
+            .map(|(variant, member)| quote!(Self::#variant { #member: field, .. } => #body,));
+        quote! {
+            match self {
+                #(#arms)*
+            }
+        }
+    }
+
+    /// Build an expression moving the field out of `self` taken by value
+    fn take(&self) -> TokenStream2 {
+        let path = &self.path;
+        if self.variants.is_empty() {
+            let member = &self.member;
+            return quote!(self.#member #(.#path)*);
+        }
+
+        let arms = self
+            .variants
+            .iter()
+            .map(|(variant, member)| quote!(Self::#variant { #member: field, .. } => field #(.#path)*,));
+        quote! {
+            match self {
+                #(#arms)*
+            }
+        }
+    }
+
+    /// Compute the `Target` type and the expressions reaching it from `self`
+    fn access(&self, options: &DerefOptions) -> syn::Result<DerefAccess> {
+        // `unwrap` and `through` together look through the value inside the `Option`
+        let modes = [
+            ("as_slice", options.as_slice),
+            ("lifetime", options.lifetime.is_some()),
+            ("through", options.through && !options.unwrap),
+            ("via", options.via.is_some()),
+            ("unwrap", options.unwrap),
+            ("target", options.target.is_some() && options.via.is_none()),
+        ];
+        let mut active = modes.iter().filter(|(_, set)| *set).map(|(mode, _)| mode);
+        if let (Some(first), Some(second)) = (active.next(), active.next()) {
+            return Err(syn::Error::new_spanned(
+                &self.ty,
+                format!("#[deref({})] cannot be combined with #[deref({})]", first, second),
+            ));
+        }
+
+        let field_ty = &self.ty;
+
+        if options.unwrap {
+            let inner = option_inner(field_ty)
+                .ok_or_else(|| syn::Error::new_spanned(field_ty, "#[deref(unwrap)] requires an Option field"))?;
+            return Ok(if options.through {
+                DerefAccess {
+                    target: syn::parse_quote!(<#inner as ::core::ops::Deref>::Target),
+                    deref_expr: self.project(|place| quote!(&**#place.as_ref().unwrap())),
+                    deref_mut_expr: self.project(|place| quote!(&mut **#place.as_mut().unwrap())),
+                }
+            } else {
+                DerefAccess {
+                    target: inner.clone(),
+                    deref_expr: self.project(|place| quote!(#place.as_ref().unwrap())),
+                    deref_mut_expr: self.project(|place| quote!(#place.as_mut().unwrap())),
+                }
+            });
+        }
+
+        if let Some(via) = &options.via {
+            if via != "as_ref" {
+                return Err(syn::Error::new_spanned(
+                    via,
+                    format!("unsupported #[deref(via = \"{}\")], expected \"as_ref\"", via),
+                ));
+            }
+
+            let target = options.target.as_ref().ok_or_else(|| {
+                syn::Error::new_spanned(via, "#[deref(via = \"as_ref\")] requires #[deref(target = \"...\")]")
+            })?;
+            return Ok(DerefAccess {
+                target: target.clone(),
+                deref_expr: self.project(|place| quote!(::core::convert::AsRef::<#target>::as_ref(&#place))),
+                deref_mut_expr: self.project(|place| quote!(::core::convert::AsMut::<#target>::as_mut(&mut #place))),
+            });
+        }
+
+        // The field reference deref-coerces to the requested target, e.g. `&String` to `&str`
+        if let Some(target) = &options.target {
+            return Ok(DerefAccess {
+                target: target.clone(),
+                deref_expr: self.project(|place| quote!(&#place)),
+                deref_mut_expr: self.project(|place| quote!(&mut #place)),
+            });
+        }
+
+        if let Some(lifetime) = &options.lifetime {
+            return match field_ty {
+                Type::Reference(reference) if reference.lifetime.as_ref() == Some(lifetime) => Ok(DerefAccess {
+                    target: (*reference.elem).clone(),
+                    deref_expr: self.project(|place| quote!(&*#place)),
+                    deref_mut_expr: self.project(|place| quote!(&mut *#place)),
+                }),
                 _ => Err(syn::Error::new_spanned(
-                    fields_named,
-                    format!("Must have one field marked with #[{}]", attr_name),
+                    field_ty,
+                    format!(
+                        "#[deref(lifetime = \"{}\")] requires a field of type `&{} T`",
+                        lifetime, lifetime
+                    ),
                 )),
-            }
+            };
         }
-        Fields::Unnamed(fields_unnamed) => {
-            let mut deref_index = None;
-            let mut field_type = None;
 
-            for (index, field) in fields_unnamed.unnamed.iter().enumerate() {
-                if has_attribute(&field.attrs, attr_name) {
-                    if deref_index.is_some() {
+        if options.as_slice {
+            let elem = slice_element(field_ty).ok_or_else(|| match field_ty {
+                // The expansion of a type macro is not visible to the derive
+                Type::Macro(syn::TypeMacro { mac }) => {
+                    let path = &mac.path;
+                    let message = format!(
+                        "#[deref(as_slice)] cannot look inside the type macro `{}!`; \
+                         write the array or Vec type directly",
+                        quote!(#path).to_string().replace(' ', "")
+                    );
+                    syn::Error::new_spanned(field_ty, message)
+                }
+                _ => syn::Error::new_spanned(field_ty, "#[deref(as_slice)] requires an array or Vec field"),
+            })?;
+            return Ok(DerefAccess {
+                target: syn::parse_quote!([#elem]),
+                deref_expr: self.project(|place| quote!(&#place[..])),
+                deref_mut_expr: self.project(|place| quote!(&mut #place[..])),
+            });
+        }
+
+        if options.through {
+            return Ok(DerefAccess {
+                target: syn::parse_quote!(<#field_ty as ::core::ops::Deref>::Target),
+                deref_expr: self.project(|place| quote!(&*#place)),
+                deref_mut_expr: self.project(|place| quote!(&mut *#place)),
+            });
+        }
+
+        Ok(DerefAccess {
+            target: field_ty.clone(),
+            deref_expr: self.project(|place| quote!(&#place)),
+            deref_mut_expr: self.project(|place| quote!(&mut #place)),
+        })
+    }
+}
+
+/// The `Target` type of the generated impls and how `deref`/`deref_mut` reach it
+struct DerefAccess {
+    target: Type,
+    deref_expr: TokenStream2,
+    deref_mut_expr: TokenStream2,
+}
+
+/// Reject `DerefMut` through shared pointers, which only implement `Deref`
+fn reject_shared_pointer(ty: &Type) -> syn::Result<()> {
+    let Type::Path(type_path) = ty else {
+        return Ok(());
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return Ok(());
+    };
+
+    if segment.ident == "Arc" || segment.ident == "Rc" {
+        return Err(syn::Error::new_spanned(
+            ty,
+            format!(
+                "`{0}` does not implement DerefMut, so #[deref(through)] cannot be used with DerefMut; \
+                 derive Deref instead and use `{0}::get_mut`/`{0}::make_mut` for mutation, \
+                 or store the value in a uniquely owned type such as `Box`",
+                segment.ident
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Options accepted inside `#[deref(...)]`, on the container or on the marked field
+#[derive(Clone, Default)]
+pub(crate) struct DerefOptions {
+    /// Deref to a slice of an array or `Vec` field
+    as_slice: bool,
+    /// Validator run by the generated `TryFrom<FieldType>` impl
+    validate: Option<syn::Path>,
+    /// Error type returned by the validator
+    error: Option<Type>,
+    /// Generate `#[must_use]` `get`/`get_mut` accessors
+    must_use_accessor: bool,
+    /// Generate `const fn` `get`/`get_mut` accessors
+    const_accessor: bool,
+    /// Deref to the referent of a `&'a T` field
+    lifetime: Option<syn::Lifetime>,
+    /// Deref to the field's own `Deref::Target`
+    through: bool,
+    /// Container-level selection of the deref field by name
+    field: Option<LitStr>,
+    /// Generate `from_ref`/`from_mut` for `#[repr(transparent)]` structs
+    from_ref: bool,
+    /// Configuration predicate gating every generated item
+    cfg: Option<syn::Meta>,
+    /// Mirror `cfg` as `doc(cfg(...))` when building docs with `--cfg docsrs`
+    doc_cfg: bool,
+    /// Name of the generated `mem::replace` method
+    replace_method: Option<syn::Ident>,
+    /// Name of the generated `mem::swap` method
+    swap_method: Option<syn::Ident>,
+    /// Name of the generated closure-scoped mutation method
+    with_mut_method: Option<syn::Ident>,
+    /// Name of the generated consuming mutation method returning `Self`
+    modify_method: Option<syn::Ident>,
+    /// Keep the first marked field instead of rejecting further markers
+    allow_duplicate_marker: bool,
+    /// Log every `deref`/`deref_mut` call in debug builds, with the given backend
+    trace: Option<TraceBackend>,
+    /// Name of the generated constructor taking the field value
+    constructor: Option<syn::Ident>,
+    /// Visibility of the constructor, the struct's own by default
+    constructor_vis: Option<syn::Visibility>,
+    /// Deref to the value inside an `Option` field, panicking on `None`
+    unwrap: bool,
+    /// Generate `Clone` cloning the deref field and defaulting the others
+    clone_field_only: bool,
+    /// Explicit `Target` type, reached by deref coercion or the `via` conversion
+    target: Option<Type>,
+    /// Conversion reaching `target` from the field, only `as_ref` is supported
+    via: Option<syn::Ident>,
+    /// Extra where-clause predicates for the generated impls
+    bound: Vec<syn::WherePredicate>,
+    /// Names of the generated `as_inner`/`as_inner_mut`/`into_inner` methods
+    inner_api: Option<InnerApi>,
+    /// Owned counterpart of a borrowed newtype, used as `ToOwned::Owned`
+    owned: Option<Type>,
+    /// Field path below the deref field, e.g. `"settings.limits"`
+    project: Option<LitStr>,
+    /// Generate `Debug` printing `Name(field)` whatever the shape of the type
+    debug_wrapped: bool,
+    /// Path of the `static` item a unit struct dereferences to
+    static_item: Option<syn::Path>,
+    /// Targets selected by a cargo feature, from `#[deref(feature = "...", target = "...")]`
+    feature_targets: Vec<(LitStr, Type)>,
+    /// The struct is `#[repr(transparent)]`, so its `PhantomData` fields are never the deref field
+    repr_transparent: bool,
+}
+
+/// Where `#[deref(trace)]` sends its messages
+#[derive(Clone, Copy)]
+enum TraceBackend {
+    /// `eprintln!`, the default
+    Stderr,
+    /// `log::trace!`, selected with `trace = "log"`
+    Log,
+}
+
+/// Method names of the explicit inner-access API
+#[derive(Clone)]
+struct InnerApi {
+    as_inner: syn::Ident,
+    as_inner_mut: syn::Ident,
+    into_inner: syn::Ident,
+}
+
+impl Default for InnerApi {
+    fn default() -> Self {
+        let ident = |name| syn::Ident::new(name, proc_macro2::Span::call_site());
+        InnerApi {
+            as_inner: ident("as_inner"),
+            as_inner_mut: ident("as_inner_mut"),
+            into_inner: ident("into_inner"),
+        }
+    }
+}
+
+impl DerefOptions {
+    pub(crate) fn parse_attrs(&mut self, attrs: &[Attribute]) -> syn::Result<()> {
+        self.repr_transparent |= is_repr_transparent(attrs);
+
+        // `#[auto_ref(...)]` and `#[deref_mut(...)]` take the same options as `#[deref(...)]`
+        for attr in attrs.iter().filter(|attr| {
+            [DEREF_ATTR, DEREF_MUT_ATTR, AUTO_REF_ATTR]
+                .iter()
+                .any(|name| attr.path().is_ident(name))
+        }) {
+            // A bare marker only marks the field
+            if matches!(attr.meta, syn::Meta::Path(_)) {
+                continue;
+            }
+
+            // A `feature` keys the `target` of its own attribute, kept apart from a plain `target`
+            let mut feature: Option<LitStr> = None;
+            let plain_target = self.target.take();
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("feature") {
+                    if feature.is_some() {
+                        return Err(meta.error("duplicate deref option"));
+                    }
+                    feature = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("as_slice") {
+                    self.as_slice = true;
+                } else if meta.path.is_ident("allow_duplicate_marker") {
+                    self.allow_duplicate_marker = true;
+                } else if meta.path.is_ident("clone_field_only") {
+                    self.clone_field_only = true;
+                } else if meta.path.is_ident("unwrap") {
+                    self.unwrap = true;
+                } else if meta.path.is_ident("debug_wrapped") {
+                    self.debug_wrapped = true;
+                } else if meta.path.is_ident("trace") {
+                    if self.trace.is_some() {
+                        return Err(meta.error("duplicate deref option"));
+                    }
+                    self.trace = Some(if meta.input.peek(syn::Token![=]) {
+                        let backend: LitStr = meta.value()?.parse()?;
+                        match backend.value().as_str() {
+                            "log" => TraceBackend::Log,
+                            "eprintln" => TraceBackend::Stderr,
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    backend,
+                                    "unknown trace backend, expected \"log\" or \"eprintln\"",
+                                ));
+                            }
+                        }
+                    } else {
+                        TraceBackend::Stderr
+                    });
+                } else if meta.path.is_ident("through") || meta.path.is_ident("forward") {
+                    self.through = true;
+                } else if meta.path.is_ident("field") {
+                    if self.field.is_some() {
+                        return Err(meta.error("duplicate deref option"));
+                    }
+                    self.field = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("cfg") {
+                    if self.cfg.is_some() {
+                        return Err(meta.error("duplicate deref option"));
+                    }
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    self.cfg = Some(content.parse()?);
+                } else if meta.path.is_ident("doc_cfg") {
+                    self.doc_cfg = true;
+                } else if meta.path.is_ident("from_ref") {
+                    self.from_ref = true;
+                } else if meta.path.is_ident("const") {
+                    self.const_accessor = true;
+                } else if meta.path.is_ident("must_use_accessor") {
+                    self.must_use_accessor = true;
+                } else if meta.path.is_ident("lifetime") {
+                    set_once(&mut self.lifetime, &meta)?;
+                } else if meta.path.is_ident("replace_method") {
+                    set_once(&mut self.replace_method, &meta)?;
+                } else if meta.path.is_ident("swap_method") {
+                    set_once(&mut self.swap_method, &meta)?;
+                } else if meta.path.is_ident("with_mut_method") {
+                    set_once(&mut self.with_mut_method, &meta)?;
+                } else if meta.path.is_ident("modify_method") {
+                    set_once(&mut self.modify_method, &meta)?;
+                } else if meta.path.is_ident("target") {
+                    set_once(&mut self.target, &meta)?;
+                } else if meta.path.is_ident("via") {
+                    set_once(&mut self.via, &meta)?;
+                } else if meta.path.is_ident("bound") {
+                    let bound: LitStr = meta.value()?.parse()?;
+                    self.bound.extend(bound.parse_with(
+                        syn::punctuated::Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated,
+                    )?);
+                } else if meta.path.is_ident("inner_api") {
+                    if self.inner_api.is_some() {
+                        return Err(meta.error("duplicate deref option"));
+                    }
+                    let api = self.inner_api.insert(InnerApi::default());
+                    // Method names can be changed with `inner_api(as_inner = "...", ...)`
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|meta| {
+                            let slot = if meta.path.is_ident("as_inner") {
+                                &mut api.as_inner
+                            } else if meta.path.is_ident("as_inner_mut") {
+                                &mut api.as_inner_mut
+                            } else if meta.path.is_ident("into_inner") {
+                                &mut api.into_inner
+                            } else {
+                                return Err(meta.error("unknown inner_api method"));
+                            };
+                            *slot = meta.value()?.parse::<LitStr>()?.parse()?;
+                            Ok(())
+                        })?;
+                    }
+                } else if meta.path.is_ident("project") {
+                    if self.project.is_some() {
+                        return Err(meta.error("duplicate deref option"));
+                    }
+                    self.project = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("static") {
+                    set_once(&mut self.static_item, &meta)?;
+                } else if meta.path.is_ident("owned") {
+                    set_once(&mut self.owned, &meta)?;
+                } else if meta.path.is_ident("constructor") {
+                    set_once(&mut self.constructor, &meta)?;
+                } else if meta.path.is_ident("constructor_vis") {
+                    set_once(&mut self.constructor_vis, &meta)?;
+                } else if meta.path.is_ident("validate") {
+                    set_once(&mut self.validate, &meta)?;
+                } else if meta.path.is_ident("error") {
+                    set_once(&mut self.error, &meta)?;
+                } else {
+                    return Err(meta.error("unknown deref option"));
+                }
+                Ok(())
+            })?;
+
+            match feature {
+                Some(feature) => {
+                    let target = self.target.take().ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            &feature,
+                            "#[deref(feature = \"...\")] requires #[deref(target = \"...\")] in the same attribute",
+                        )
+                    })?;
+                    if self
+                        .feature_targets
+                        .iter()
+                        .any(|(known, _)| known.value() == feature.value())
+                    {
                         return Err(syn::Error::new_spanned(
-                            field,
-                            format!("Only one field can be marked with #[{}]", attr_name),
+                            &feature,
+                            format!("a target is already selected for feature `{}`", feature.value()),
                         ));
                     }
-
-                    deref_index = Some(index);
-                    field_type = Some(field.ty.clone());
+                    self.feature_targets.push((feature, target));
+                    self.target = plain_target;
                 }
+                None => match (plain_target, &self.target) {
+                    (Some(_), Some(target)) => {
+                        return Err(syn::Error::new_spanned(target, "duplicate deref option"));
+                    }
+                    (Some(target), None) => self.target = Some(target),
+                    (None, _) => {}
+                },
             }
+        }
 
-            match (deref_index, field_type) {
-                (Some(index), Some(ty)) => Ok((DerefField::Unnamed(index), ty)),
-                _ => Err(syn::Error::new_spanned(
-                    fields_unnamed,
-                    format!("Must have one field marked with #[{}]", attr_name),
-                )),
+        Ok(())
+    }
+}
+
+/// Parse the string value of `key = "..."` into `slot`, rejecting repeated keys
+fn set_once<T: Parse>(slot: &mut Option<T>, meta: &ParseNestedMeta) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error("duplicate deref option"));
+    }
+
+    *slot = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+    Ok(())
+}
+
+/// Type wrapped by an `Option<T>` field
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if type_path.qself.is_some() || segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Element type of an array (`[T; N]`) or `Vec<T>` field
+fn slice_element(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Array(array) => Some(&array.elem),
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let segment = type_path.path.segments.last()?;
+            if segment.ident != "Vec" {
+                return None;
+            }
+
+            match &segment.arguments {
+                PathArguments::AngleBracketed(args) => match args.args.first()? {
+                    GenericArgument::Type(elem) if args.args.len() == 1 => Some(elem),
+                    _ => None,
+                },
+                _ => None,
             }
         }
-        Fields::Unit => Err(syn::Error::new_spanned(fields, "Unit structs are not supported")),
+        _ => None,
     }
 }
 
 /// Type representing the Deref field
-enum DerefField {
+#[derive(Clone, PartialEq)]
+pub(crate) enum DerefField {
     Named(syn::Ident),
     Unnamed(usize),
 }
 
+impl DerefField {
+    fn new(index: usize, field: &Field) -> Self {
+        match &field.ident {
+            Some(ident) => DerefField::Named(ident.clone()),
+            None => DerefField::Unnamed(index),
+        }
+    }
+}
+
 impl quote::ToTokens for DerefField {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         match self {
@@ -140,6 +1738,63 @@ impl quote::ToTokens for DerefField {
     }
 }
 
+/// Options carried by the marker attributes of a field, bare markers being equivalent
+fn marker_options(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| {
+            [DEREF_ATTR, DEREF_MUT_ATTR, AUTO_REF_ATTR]
+                .iter()
+                .any(|name| attr.path().is_ident(name))
+        })
+        .filter(|attr| !matches!(attr.meta, syn::Meta::Path(_)))
+        .map(|attr| attr.meta.to_token_stream().to_string())
+        .collect()
+}
+
+/// Function to check if a field type is `PhantomData<...>`, by its last path segment
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+/// Function to check if the struct is `#[repr(transparent)]`
+fn is_repr_transparent(attrs: &[Attribute]) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("repr")).any(|attr| {
+        let mut transparent = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            transparent |= meta.path.is_ident("transparent");
+            Ok(())
+        });
+        transparent
+    })
+}
+
+/// Function to check if a field carries the deref marker, `#[deref]` or one of its aliases
+fn is_marked(attrs: &[Attribute]) -> bool {
+    [DEREF_ATTR, DEREF_MUT_ATTR, AUTO_REF_ATTR]
+        .iter()
+        .any(|name| has_attribute(attrs, name))
+}
+
+/// Function to find the `#[deref_mut]` of two marked fields when the other one is marked otherwise
+fn mismatched_deref_mut<'a>(first: &'a [Attribute], second: &'a [Attribute]) -> Option<&'a Attribute> {
+    let deref_mut = |attrs: &'a [Attribute]| attrs.iter().find(|attr| attr.path().is_ident(DEREF_MUT_ATTR));
+    let shared = |attrs: &[Attribute]| has_attribute(attrs, DEREF_ATTR) || has_attribute(attrs, AUTO_REF_ATTR);
+
+    match (deref_mut(first), deref_mut(second)) {
+        (None, Some(attr)) if shared(first) => Some(attr),
+        (Some(attr), None) if shared(second) => Some(attr),
+        _ => None,
+    }
+}
+
 /// Function to check if an attribute exists
 fn has_attribute(attrs: &[Attribute], name: &str) -> bool {
     attrs.iter().any(|attr| attr.path().is_ident(name))
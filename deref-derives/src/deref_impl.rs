@@ -0,0 +1,119 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{GenericArgument, GenericParam, Generics, Member, PathArguments, Token, Type, WhereClause};
+
+/// Input of `deref_impl!`: `impl<T> Type<'a, T>, Target, field` with an optional where-clause
+pub struct DerefImplInput {
+    /// Type parameters listed after a leading `impl`
+    generics: Option<Generics>,
+    ty: Type,
+    target: Type,
+    member: Member,
+    where_clause: Option<WhereClause>,
+}
+
+impl Parse for DerefImplInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let generics = match input.parse::<Option<Token![impl]>>()? {
+            Some(_) => Some(input.parse()?),
+            None => None,
+        };
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let target = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let member = input.parse()?;
+        let where_clause = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(DerefImplInput {
+            generics,
+            ty,
+            target,
+            member,
+            where_clause,
+        })
+    }
+}
+
+/// Implementation function for `deref_impl!`
+pub fn impl_deref_impl(input: &DerefImplInput) -> syn::Result<TokenStream2> {
+    let DerefImplInput {
+        generics,
+        ty,
+        target,
+        member,
+        where_clause,
+    } = input;
+    let params = match generics {
+        Some(generics) => generics.params.clone(),
+        None => inferred_params(ty)?,
+    };
+
+    Ok(quote! {
+        impl<#params> ::core::ops::Deref for #ty #where_clause {
+            type Target = #target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.#member
+            }
+        }
+    })
+}
+
+/// Parameters of the impl, read from the arguments of the implementing type
+///
+/// Named lifetimes other than `'static` become lifetime parameters, and type arguments named like
+/// type parameters, a single uppercase letter optionally followed by digits (`T`, `K`, `T2`),
+/// become type parameters. Any other type argument is concrete: `W<String>` is the type
+/// `W<String>`. Const parameters cannot be told apart from types, so they need the `impl` list.
+fn inferred_params(ty: &Type) -> syn::Result<Punctuated<GenericParam, Token![,]>> {
+    let Type::Path(type_path) = ty else {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "deref_impl! expects a type path such as `Wrapper<'a, T>`",
+        ));
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return Ok(Punctuated::new());
+    };
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return Ok(Punctuated::new());
+    };
+
+    // Lifetimes have to come first in the impl header
+    let lifetimes = args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Lifetime(lifetime) if lifetime.ident != "static" && lifetime.ident != "_" => {
+            Some(GenericParam::Lifetime(syn::parse_quote!(#lifetime)))
+        }
+        _ => None,
+    });
+    let types = args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(Type::Path(path)) if path.qself.is_none() => path
+            .path
+            .get_ident()
+            .filter(|ident| is_type_param_name(ident))
+            .map(|ident| GenericParam::Type(syn::parse_quote!(#ident))),
+        _ => None,
+    });
+
+    // A parameter repeated in the arguments, as in `Pair<T, T>`, is declared once
+    let mut params = Punctuated::new();
+    for param in lifetimes.chain(types) {
+        let name = quote!(#param).to_string();
+        if !params.iter().any(|declared| quote!(#declared).to_string() == name) {
+            params.push(param);
+        }
+    }
+    Ok(params)
+}
+
+/// Function to check if a type name follows the type parameter convention, such as `T` or `T2`
+fn is_type_param_name(ident: &syn::Ident) -> bool {
+    let name = ident.to_string();
+    let mut chars = name.chars();
+    chars.next().is_some_and(|first| first.is_ascii_uppercase()) && chars.all(|rest| rest.is_ascii_digit())
+}
@@ -0,0 +1,139 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DataStruct, DeriveInput, LitStr, Type};
+
+use crate::deref::{DerefField, DerefOptions, find_deref_field};
+
+/// Attribute listing the traits to forward
+const FORWARD_ATTR: &str = "forward";
+
+/// Traits whose methods are known to the derive
+const TRAITS: &[&str] = &["Read", "Write", "Iterator"];
+
+/// Implementation function for the `Forward` derive
+pub fn impl_forward(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let Data::Struct(DataStruct { fields, .. }) = &input.data else {
+        return Err(syn::Error::new_spanned(input, "Forward can only be used on structs"));
+    };
+
+    let traits = parse_traits(input)?;
+
+    let mut options = DerefOptions::default();
+    options.parse_attrs(&input.attrs)?;
+    let deref_field = find_deref_field(&input.ident, fields, &mut options)?;
+    let member = &deref_field.member;
+    let field_ty = &deref_field.ty;
+
+    let impls = traits.iter().map(|trait_name| {
+        let (path, body) = forwarding(&trait_name.value(), member, field_ty);
+
+        let mut generics = input.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#field_ty: #path));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics #path for #name #ty_generics #where_clause {
+                #body
+            }
+        }
+    });
+
+    Ok(quote!(#(#impls)*))
+}
+
+/// Collect the `trait = "..."` entries of every `#[forward(...)]` attribute
+fn parse_traits(input: &DeriveInput) -> syn::Result<Vec<LitStr>> {
+    let mut traits = Vec::new();
+
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident(FORWARD_ATTR)) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("trait") {
+                return Err(meta.error("unknown forward option, expected `trait = \"...\"`"));
+            }
+
+            let trait_name: LitStr = meta.value()?.parse()?;
+            if !TRAITS.contains(&trait_name.value().as_str()) {
+                return Err(syn::Error::new_spanned(
+                    &trait_name,
+                    format!(
+                        "cannot forward `{}`, supported traits are {}",
+                        trait_name.value(),
+                        TRAITS
+                            .iter()
+                            .map(|name| format!("`{}`", name))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                ));
+            }
+            if traits.iter().any(|known: &LitStr| known.value() == trait_name.value()) {
+                return Err(syn::Error::new_spanned(
+                    &trait_name,
+                    format!("`{}` is already forwarded", trait_name.value()),
+                ));
+            }
+
+            traits.push(trait_name);
+            Ok(())
+        })?;
+    }
+
+    if traits.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Forward requires #[forward(trait = \"...\")]",
+        ));
+    }
+
+    Ok(traits)
+}
+
+/// Path of a supported trait and the items of its impl, delegating to `self.#member`
+fn forwarding(trait_name: &str, member: &DerefField, field_ty: &Type) -> (TokenStream2, TokenStream2) {
+    match trait_name {
+        "Read" => (
+            quote!(::std::io::Read),
+            quote! {
+                #[inline]
+                fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+                    ::std::io::Read::read(&mut self.#member, buf)
+                }
+            },
+        ),
+        "Write" => (
+            quote!(::std::io::Write),
+            quote! {
+                #[inline]
+                fn write(&mut self, buf: &[u8]) -> ::std::io::Result<usize> {
+                    ::std::io::Write::write(&mut self.#member, buf)
+                }
+
+                #[inline]
+                fn flush(&mut self) -> ::std::io::Result<()> {
+                    ::std::io::Write::flush(&mut self.#member)
+                }
+            },
+        ),
+        "Iterator" => (
+            quote!(::core::iter::Iterator),
+            quote! {
+                type Item = <#field_ty as ::core::iter::Iterator>::Item;
+
+                #[inline]
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    ::core::iter::Iterator::next(&mut self.#member)
+                }
+
+                #[inline]
+                fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                    ::core::iter::Iterator::size_hint(&self.#member)
+                }
+            },
+        ),
+        _ => unreachable!("trait names are checked while parsing"),
+    }
+}
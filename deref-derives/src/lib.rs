@@ -1,11 +1,110 @@
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod convert;
 mod deref;
+mod deref_impl;
+#[cfg(feature = "forward")]
+mod forward;
+mod ops;
 
 /// Derive macro to implement the Deref trait
 ///
-/// Use the `#[auto_ref]` attribute to mark the field to implement Deref for
+/// Mark the field to implement Deref for with `#[deref]`. `#[auto_ref]` is accepted as an alias,
+/// taking the same options as `#[deref(...)]`, and either marker may appear on only one field. The marker can be left out when there is a
+/// single field, as in `struct Meters(f64);`, or when the other fields of a `#[repr(transparent)]`
+/// struct are `PhantomData`, as in `struct Id<T>(u64, PhantomData<T>);`.
+///
+/// Single-variant enums are supported as well, e.g. `enum Name { V(Inner) }`, and so are enums
+/// whose variants each hold one field of the same type, e.g. `enum Id { User(u64), Post(u64) }`.
+///
+/// By default `Target` is the field's own type and `deref` returns `&self.field`. This holds for
+/// reference fields too: a `&'a T` field has `Target = &'a T`, so `deref` yields `&&'a T`, which
+/// auto-deref and method calls see through. Use `through` or `lifetime = "'a"` to make `T` itself
+/// the target instead.
+///
+/// `Target` is part of the public interface of the type, so it must be at least as visible: a
+/// `pub struct` wrapping a private type fails to compile with rustc's E0446, reported at the field
+/// type. The derive cannot see the visibility of that type, so it adds no diagnostic of its own.
+///
+/// The field can also be marked with `#[deref(...)]`. Options are accepted on the marked field
+/// or on the struct itself:
+/// - `as_slice`: deref an array or `Vec` field to a slice of its elements
+/// - `trace`: in debug builds, log every `deref`/`deref_mut` call to stderr with `eprintln!`, which
+///   needs `std`; `trace = "log"` uses `log::trace!` instead (the crate must then depend on `log`),
+///   which also works in `#![no_std]` crates. Release builds return the reference without logging
+/// - `through` (or its alias `forward`): deref to the field's own `Deref::Target`, e.g. `Box<T>`
+///   to `T`
+/// - `unwrap`: for a field of type `Option<U>`, deref to `U`, e.g. `&'a T` for `Option<&'a T>`;
+///   adding `through` derefs further to `U::Target` (`T`). Dereferencing panics when the field is
+///   `None`; the generated methods are `#[track_caller]`, so the panic points at the dereference
+/// - `target = "Type"`: deref to `Type` instead of the field type; the field reference is
+///   deref-coerced, so a `String` field can target `str` and a `Vec<T>` field `[T]`. Lifetimes in
+///   the target must be declared on the struct, and the field must actually hold data of that
+///   lifetime: `target = "&'a str"` needs a `&'a str` field, since an owned field cannot be
+///   borrowed for `'a` through `&self`
+/// - `feature = "name"` with `target = "Type"` in the same attribute (struct only): deref to `Type`
+///   when the cargo feature `name` of the deriving crate is enabled. The attribute may be repeated
+///   for other features, and the plain `target` (or the field type) applies when none of them is
+///   enabled; when several are enabled, the last one listed wins, keeping the features additive
+/// - `via = "as_ref"` (with `target`): reach the target through the field's `AsRef<Type>` impl
+///   (and `AsMut<Type>` for `DerefMut`) instead, e.g. `target = "str"` on a `C: AsRef<str>` field
+/// - `bound = "predicates"`: add where-clause predicates such as `"C: AsRef<str>"` to the generated
+///   impls without repeating them on the struct; may be given more than once
+/// - `cfg(predicate)`: gate every generated item on `#[cfg(predicate)]`; adding `doc_cfg` also emits
+///   `#[cfg_attr(docsrs, doc(cfg(predicate)))]` so docs.rs shows the requirement
+/// - `allow_duplicate_marker` (struct only): when several fields are marked, e.g. because another
+///   macro injected a marked field, use the first one instead of reporting an error; later markers
+///   must repeat the first one exactly, since their options would otherwise be dropped
+/// - `field = "name"` (struct only): select the deref field by name instead of marking it;
+///   keywords select raw identifier fields (`"type"` is `r#type`) and `"0"` selects a tuple field.
+///   A dotted path such as `"state.inner"` reaches a nested field; the derive cannot see the types
+///   of nested structs, so `target` must name the last field's type (or a type it coerces to)
+/// - `debug_wrapped`: generate `Debug` formatting the deref field alone in tuple style, as
+///   `Name(field)`, whatever the shape of the type; the field type must implement `Debug`
+/// - `project = "a.b"`: deref to a field nested below the deref field, e.g. `&self.config.a.b` for
+///   a marked `config` field; like a dotted `field` path, it requires `target` and accepts field
+///   names and tuple indices only
+/// - `static = "PATH"` (unit structs only, with `target`): deref to the `static` item at `PATH`,
+///   e.g. `struct Config;` to a `static CONFIG: Settings` with `target = "Settings"`; `DerefMut`
+///   is not supported since a static is only borrowed immutably
+/// - `replace_method = "name"` / `swap_method = "name"` (`DerefMut` only): generate inherent methods
+///   wrapping `mem::replace`/`mem::swap` on the field
+/// - `with_mut_method = "name"` (`DerefMut` only): generate
+///   `fn name<R>(&mut self, f: impl FnOnce(&mut Target) -> R) -> R` for closure-scoped mutation
+/// - `modify_method = "name"` (`DerefMut` only): generate the consuming
+///   `fn name(mut self, f: impl FnOnce(&mut Target)) -> Self`, for chained mutations while building
+/// - `constructor = "name"`: generate `fn name(value: FieldType) -> Self`, setting the other
+///   fields to `Default::default()`; it has the struct's visibility unless `constructor_vis = "..."`
+///   is given, e.g. `constructor_vis = "pub(crate)"`
+/// - `validate = "path"` and `error = "Type"`: generate `TryFrom<FieldType>` that runs
+///   `path(&value) -> Result<(), Type>` before wrapping; other fields are set to `Default::default()`
+/// - `must_use_accessor`: generate a `#[must_use]` inherent `get` accessor (and `get_mut` for
+///   `DerefMut`), since the deref operator does not propagate `#[must_use]`. Doc comments on the
+///   deref field are repeated on the generated accessors
+/// - `clone_field_only`: generate a `Clone` impl that clones only the deref field and sets the other
+///   fields to `Default::default()`, for side fields that are expensive or impossible to clone
+/// - `const`: generate `const fn get(&self) -> &Target` (and `get_mut` for `DerefMut`) for use in
+///   const contexts. `Deref::deref` itself cannot be a `const fn` on stable Rust, so the trait impl
+///   is unchanged; the option combines with `must_use_accessor` and cannot be used with `as_slice`,
+///   `through`, `target` or `via`, which call non-const trait methods
+/// - `from_ref`: on a `#[repr(transparent)]` struct, generate `from_ref(&FieldType) -> &Self` (and
+///   `from_mut` for `DerefMut`); the other fields must be `PhantomData`, and `validate` is rejected
+///   since the conversion would bypass it
+/// - `owned = "Type"`: generate `ToOwned` with `Owned = Type` for a borrowed newtype such as
+///   `struct Name(str)`, so that it works with `Cow`. `to_owned` converts the field's own owned
+///   value with `Type: From<<FieldType as ToOwned>::Owned>`, and `Type` must implement
+///   `Borrow<Self>`, typically through the `from_ref` of a `#[repr(transparent)]` newtype. The
+///   impl refers to `std::borrow::ToOwned`
+/// - `lifetime = "'a"`: for a field of type `&'a T`, deref to `T` itself; `'a` must be declared on
+///   the struct. `Deref::deref` always returns a reference tied to `&self`, so this selects which
+///   reference field is looked through rather than extending the returned lifetime
+/// - `inner_api`: generate `as_inner(&self) -> &FieldType`, `as_inner_mut(&mut self)` (for
+///   `DerefMut`) and `into_inner(self) -> FieldType`, which return the field itself whatever the
+///   deref target; `into_inner` drops the other fields, so the type must not implement `Drop`.
+///   Rename the methods with `inner_api(as_inner = "inner", into_inner = "take")`
 ///
 /// # Examples
 /// ```rust
@@ -16,8 +115,14 @@ mod deref;
 ///     #[auto_ref]
 ///     inner: T,
 /// }
+///
+/// #[derive(Deref)]
+/// struct Fixed<const N: usize>(#[deref(as_slice)] [u8; N]);
+///
+/// let fixed = Fixed([1, 2, 3]);
+/// assert_eq!(fixed.len(), 3);
 /// ```
-#[proc_macro_derive(Deref, attributes(auto_ref))]
+#[proc_macro_derive(Deref, attributes(auto_ref, deref))]
 pub fn derive_deref(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
 
@@ -29,7 +134,10 @@ pub fn derive_deref(input: TokenStream) -> TokenStream {
 
 /// Derive macro to implement the DerefMut trait
 ///
-/// Use the `#[auto_ref]` attribute to mark the field to implement DerefMut for
+/// Mark the field to implement DerefMut for with `#[deref]`, `#[deref_mut]` or the `#[auto_ref]`
+/// alias. The `#[deref(...)]` options of the `Deref` derive are supported as well, and can also be
+/// written as `#[deref_mut(...)]`, where they apply to both traits. `#[deref]` and `#[deref_mut]`
+/// may both appear, but only on the same field, as both traits share one target.
 ///
 /// Note: This macro automatically implements both Deref and DerefMut traits.
 /// You don't need to separately derive Deref when using DerefMut.
@@ -45,7 +153,7 @@ pub fn derive_deref(input: TokenStream) -> TokenStream {
 /// }
 /// // The above automatically implements both Deref and DerefMut
 /// ```
-#[proc_macro_derive(DerefMut, attributes(auto_ref))]
+#[proc_macro_derive(DerefMut, attributes(auto_ref, deref, deref_mut))]
 pub fn derive_deref_mut(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
 
@@ -54,3 +162,482 @@ pub fn derive_deref_mut(input: TokenStream) -> TokenStream {
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+/// Function-like macro to implement the Deref trait, inferring the impl lifetimes from the type
+///
+/// `deref_impl!(Wrapper<'a, T>, Target, field)` is equivalent to
+/// `deref!(<'a, T>, Wrapper<'a, T>, Target, field)`: named lifetimes other than `'static` and type
+/// arguments named like type parameters, a single uppercase letter optionally followed by digits
+/// (`T`, `K`, `T2`), become parameters of the impl. Other type arguments are concrete, so
+/// `deref_impl!(Wrapper<String>, String, 0)` implements `Deref` for `Wrapper<String>` only.
+/// Const parameters, and parameters with other names, are listed after a leading `impl`, as in
+/// `deref_impl!(impl<T, const N: usize> Array<T, N>, [T; N], 0)`, which replaces the inferred
+/// parameters. Bounds go in the `impl` list or in an optional where-clause after the field.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::deref_impl;
+///
+/// struct Label<'a> {
+///     text: &'a str,
+/// }
+///
+/// deref_impl!(Label<'a>, str, text);
+///
+/// struct Wrapper<'a, T> {
+///     items: &'a [T],
+/// }
+///
+/// deref_impl!(Wrapper<'a, T>, &'a [T], items);
+///
+/// struct Labeled<T>(T, &'static str);
+///
+/// deref_impl!(Labeled<T>, T, 0 where T: Copy);
+///
+/// let label = Label { text: "label" };
+/// assert_eq!(label.len(), 5);
+/// let wrapper = Wrapper { items: &[1, 2, 3] };
+/// assert_eq!(wrapper.len(), 3);
+/// assert_eq!(*Labeled(4u8, "four"), 4);
+/// ```
+#[proc_macro]
+pub fn deref_impl(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as deref_impl::DerefImplInput);
+
+    match deref_impl::impl_deref_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement the Neg trait for numeric newtypes
+///
+/// The inner field is negated and the result is rewrapped, so `-Meters(1.0)` is `Meters(-1.0)`
+/// rather than a bare `f64`. Only structs with a single field are supported, and the field type
+/// must implement `Neg<Output = FieldType>`.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::{Deref, NegFromDeref};
+///
+/// #[derive(Deref, NegFromDeref, Debug, PartialEq)]
+/// struct Meters(#[deref] f64);
+///
+/// assert_eq!(-Meters(2.5), Meters(-2.5));
+/// ```
+#[proc_macro_derive(NegFromDeref)]
+pub fn derive_neg_from_deref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_neg_trait(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement the Add trait for numeric newtypes (requires the `numeric_ops` feature)
+///
+/// `a + b` applies `+` to the inner fields and rewraps the result, so both operands and the
+/// output are the newtype rather than the bare inner value. Only structs with a single field are
+/// supported, and the field type must implement `Add<Output = FieldType>`.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::AddFromDeref;
+///
+/// #[derive(AddFromDeref, Debug, PartialEq)]
+/// struct Meters(f64);
+///
+/// assert_eq!(Meters(1.5) + Meters(2.0), Meters(3.5));
+/// ```
+#[cfg(feature = "numeric_ops")]
+#[proc_macro_derive(AddFromDeref)]
+pub fn derive_add_from_deref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_binary_op_trait(&input, "AddFromDeref", "Add") {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement the Sub trait for numeric newtypes (requires the `numeric_ops` feature)
+///
+/// `a - b` applies `-` to the inner fields and rewraps the result, so both operands and the
+/// output are the newtype rather than the bare inner value. Only structs with a single field are
+/// supported, and the field type must implement `Sub<Output = FieldType>`.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::SubFromDeref;
+///
+/// #[derive(SubFromDeref, Debug, PartialEq)]
+/// struct Meters(f64);
+///
+/// assert_eq!(Meters(5.0) - Meters(1.5), Meters(3.5));
+/// ```
+#[cfg(feature = "numeric_ops")]
+#[proc_macro_derive(SubFromDeref)]
+pub fn derive_sub_from_deref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_binary_op_trait(&input, "SubFromDeref", "Sub") {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement the Mul trait for numeric newtypes (requires the `numeric_ops` feature)
+///
+/// `a * b` applies `*` to the inner fields and rewraps the result, so both operands and the
+/// output are the newtype rather than the bare inner value. Only structs with a single field are
+/// supported, and the field type must implement `Mul<Output = FieldType>`.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::MulFromDeref;
+///
+/// #[derive(MulFromDeref, Debug, PartialEq)]
+/// struct Meters(f64);
+///
+/// assert_eq!(Meters(1.5) * Meters(2.0), Meters(3.0));
+/// ```
+#[cfg(feature = "numeric_ops")]
+#[proc_macro_derive(MulFromDeref)]
+pub fn derive_mul_from_deref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_binary_op_trait(&input, "MulFromDeref", "Mul") {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement the Div trait for numeric newtypes (requires the `numeric_ops` feature)
+///
+/// `a / b` applies `/` to the inner fields and rewraps the result, so both operands and the
+/// output are the newtype rather than the bare inner value. Only structs with a single field are
+/// supported, and the field type must implement `Div<Output = FieldType>`.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::DivFromDeref;
+///
+/// #[derive(DivFromDeref, Debug, PartialEq)]
+/// struct Meters(f64);
+///
+/// assert_eq!(Meters(3.0) / Meters(2.0), Meters(1.5));
+/// ```
+#[cfg(feature = "numeric_ops")]
+#[proc_macro_derive(DivFromDeref)]
+pub fn derive_div_from_deref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_binary_op_trait(&input, "DivFromDeref", "Div") {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement the AddAssign trait for mutable newtypes
+///
+/// `wrapper += rhs` forwards to `field += rhs` for every `Rhs` the field type supports, so a
+/// `String` newtype accepts `+= "!"` and a numeric newtype accepts its own field type. Only structs
+/// with a single field are supported.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::{AddAssignFromDeref, DerefMut};
+///
+/// #[derive(DerefMut, AddAssignFromDeref)]
+/// struct Greeting(#[deref] String);
+///
+/// let mut greeting = Greeting("Hello".to_string());
+/// greeting += "!";
+/// *greeting += "!";
+/// assert_eq!(greeting.as_str(), "Hello!!");
+/// ```
+#[proc_macro_derive(AddAssignFromDeref)]
+pub fn derive_add_assign_from_deref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_add_assign_trait(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement `PartialEq<FieldType>` by comparing the field with a raw value
+///
+/// The field is selected like for the `Deref` derive and its type must implement `PartialEq`. The
+/// reverse `PartialEq<Name> for FieldType` is generated as well, so the raw value may come first,
+/// except when the field type mentions a type parameter, as in `T` or `Box<T>`, where the orphan
+/// rules may forbid it.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::EqInner;
+///
+/// #[derive(EqInner)]
+/// struct Name(String);
+///
+/// let name = Name("hello".to_string());
+/// assert!(name == "hello".to_string());
+/// assert!("hello".to_string() == name);
+/// ```
+#[proc_macro_derive(EqInner, attributes(auto_ref, deref))]
+pub fn derive_eq_inner(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_eq_inner(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement the Iterator trait by delegating to the field's iterator
+///
+/// The field is marked with `#[deref]` or `#[auto_ref]` like for the `Deref` derive, and its type
+/// must implement `Iterator`. `next` and `size_hint` are forwarded to it.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::{Deref, IteratorFromDeref};
+///
+/// #[derive(Deref, IteratorFromDeref)]
+/// struct Tokens {
+///     #[deref]
+///     iter: std::vec::IntoIter<&'static str>,
+///     source: &'static str,
+/// }
+///
+/// let tokens = Tokens { iter: vec!["a", "b"].into_iter(), source: "a b" };
+/// assert_eq!(tokens.len(), 2);
+/// assert_eq!(tokens.collect::<Vec<_>>(), ["a", "b"]);
+/// ```
+#[proc_macro_derive(IteratorFromDeref, attributes(auto_ref, deref))]
+pub fn derive_iterator_from_deref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_iterator_trait(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement the Index trait by delegating to the field
+///
+/// The field is selected like for the `Deref` derive. `Index<I>` is implemented for every `I`
+/// the field type accepts, with the same `Output`, so `wrapper[i]` works without going through
+/// `*wrapper` first.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::Index;
+///
+/// #[derive(Index)]
+/// struct Scores(Vec<u32>);
+///
+/// let scores = Scores(vec![10, 20, 30]);
+/// assert_eq!(scores[1], 20);
+/// assert_eq!(&scores[1..], [20, 30]);
+/// ```
+#[proc_macro_derive(Index, attributes(auto_ref, deref))]
+pub fn derive_index(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_index_trait(&input, false) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement both Index and IndexMut traits by delegating to the field
+///
+/// The field is selected like for the `DerefMut` derive. `IndexMut` requires `Index`, so both
+/// impls are generated and `Index` must not be derived as well.
+#[proc_macro_derive(IndexMut, attributes(auto_ref, deref, deref_mut))]
+pub fn derive_index_mut(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match ops::impl_index_trait(&input, true) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement `AsRef<FieldType>` by borrowing the field
+///
+/// The field is the one marked with `#[as_ref]`. Without that marker it is selected like for the
+/// `Deref` derive: marked with `#[deref]` or `#[auto_ref]`, or the sole field, so both derives can
+/// share a single marker on the same struct.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::{AsRef, Deref};
+///
+/// #[derive(Deref, AsRef)]
+/// struct Name {
+///     #[deref]
+///     value: String,
+///     checked: bool,
+/// }
+///
+/// fn len(text: impl AsRef<String>) -> usize {
+///     text.as_ref().len()
+/// }
+///
+/// let name = Name { value: "Ada".to_string(), checked: true };
+/// assert_eq!(len(&name), 3);
+/// ```
+#[proc_macro_derive(AsRef, attributes(as_ref, auto_ref, deref))]
+pub fn derive_as_ref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match convert::impl_as_ref_trait(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement `AsMut<FieldType>` by mutably borrowing the field
+///
+/// The field is selected like for the `DerefMut` derive: marked with `#[deref_mut]`, `#[deref]` or
+/// `#[auto_ref]`, or the sole field.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::AsMut;
+///
+/// #[derive(AsMut)]
+/// struct Tally(u32);
+///
+/// fn bump(mut counter: impl AsMut<u32>) {
+///     *counter.as_mut() += 1;
+/// }
+///
+/// let mut tally = Tally(1);
+/// bump(&mut tally);
+/// assert_eq!(tally.0, 2);
+/// ```
+#[proc_macro_derive(AsMut, attributes(auto_ref, deref, deref_mut))]
+pub fn derive_as_mut(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match convert::impl_as_mut_trait(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement `Borrow<FieldType>` by borrowing the field
+///
+/// The field is selected like for the `Deref` derive. Combined with `Hash`, `Eq` and `Ord` impls
+/// that agree with the field's, as derived ones on a single-field newtype do, this lets maps and
+/// sets keyed by the wrapper be queried with the inner type.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::Borrow;
+/// use std::collections::HashSet;
+///
+/// #[derive(Borrow, Hash, PartialEq, Eq)]
+/// struct Tag(String);
+///
+/// let tags = HashSet::from([Tag("rust".to_string())]);
+/// assert!(tags.contains(&"rust".to_string()));
+/// ```
+#[proc_macro_derive(Borrow, attributes(auto_ref, deref))]
+pub fn derive_borrow(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match convert::impl_borrow_trait(&input, false) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro to implement both `Borrow<FieldType>` and `BorrowMut<FieldType>`
+///
+/// The field is selected like for the `DerefMut` derive. `BorrowMut` requires `Borrow`, so both
+/// impls are generated and `Borrow` must not be derived as well.
+#[proc_macro_derive(BorrowMut, attributes(auto_ref, deref, deref_mut))]
+pub fn derive_borrow_mut(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match convert::impl_borrow_trait(&input, true) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro forwarding whole traits to a field (requires the `forward` feature)
+///
+/// List the traits with `#[forward(trait = "...")]`, repeated or in one attribute. The field is
+/// selected like for the `Deref` derive, and its type must implement each listed trait. Method
+/// signatures cannot be looked up at macro time, so only a built-in set of traits is supported:
+/// - `Read`: forwards `std::io::Read::read`
+/// - `Write`: forwards `std::io::Write::write` and `flush`
+/// - `Iterator`: forwards `next` and `size_hint`
+///
+/// The provided methods of `Read` and `Write` keep their default implementations, built on the
+/// forwarded ones.
+///
+/// # Examples
+/// ```rust
+/// use deref_derives::Forward;
+/// use std::io::Read;
+///
+/// #[derive(Forward)]
+/// #[forward(trait = "Read")]
+/// struct Counted<R> {
+///     #[deref]
+///     reader: R,
+///     label: &'static str,
+/// }
+///
+/// let mut input = Counted { reader: &b"data"[..], label: "input" };
+/// let mut text = String::new();
+/// input.read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "data");
+/// ```
+#[cfg(feature = "forward")]
+#[proc_macro_derive(Forward, attributes(forward, auto_ref, deref))]
+pub fn derive_forward(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match forward::impl_forward(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derive macro implementing `arbitrary::Arbitrary` from an arbitrary field value (requires the
+/// `arbitrary` feature)
+///
+/// The field is selected like for the `Deref` derive and its type must implement `Arbitrary`; the
+/// other fields start from `Default`. Fuzzers and property tests can then generate the wrapper as
+/// they would its field. The crate must depend on `arbitrary` itself.
+///
+/// # Examples
+/// ```rust
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use deref_derives::ArbitraryFromDeref;
+///
+/// #[derive(ArbitraryFromDeref)]
+/// struct Port(u16);
+///
+/// let mut input = Unstructured::new(&[1, 2]);
+/// let port = Port::arbitrary(&mut input).unwrap();
+/// assert_eq!(port.0, u16::from_le_bytes([1, 2]));
+/// ```
+#[cfg(feature = "arbitrary")]
+#[proc_macro_derive(ArbitraryFromDeref, attributes(auto_ref, deref))]
+pub fn derive_arbitrary_from_deref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match arbitrary::impl_arbitrary_from_deref(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
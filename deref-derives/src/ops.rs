@@ -0,0 +1,268 @@
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::{Data, DataStruct, DeriveInput, Fields, Member, Type};
+
+use crate::deref::{DerefOptions, find_deref_field};
+
+/// Implementation function for the `Neg` forwarding derive
+pub fn impl_neg_trait(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (member, field_ty) = newtype_field(input, "NegFromDeref")?;
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_ty: ::core::ops::Neg<Output = #field_ty>));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::ops::Neg for #name #ty_generics #where_clause {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self { #member: -self.#member }
+            }
+        }
+    })
+}
+
+/// Implementation function for the binary operator derives, `Self op Self` rewrapping the result
+#[cfg(feature = "numeric_ops")]
+pub fn impl_binary_op_trait(input: &DeriveInput, derive_name: &str, op_trait: &str) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (member, field_ty) = newtype_field(input, derive_name)?;
+    let op_trait = syn::Ident::new(op_trait, proc_macro2::Span::call_site());
+    let method = syn::Ident::new(&op_trait.to_string().to_lowercase(), proc_macro2::Span::call_site());
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_ty: ::core::ops::#op_trait<Output = #field_ty>));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::ops::#op_trait for #name #ty_generics #where_clause {
+            type Output = Self;
+
+            #[inline]
+            fn #method(self, rhs: Self) -> Self::Output {
+                Self { #member: ::core::ops::#op_trait::#method(self.#member, rhs.#member) }
+            }
+        }
+    })
+}
+
+/// Implementation function for the `AddAssign` forwarding derive
+///
+/// The right-hand side is generic, so every `AddAssign<Rhs>` impl of the field type is forwarded,
+/// including `AddAssign<FieldType>` itself.
+pub fn impl_add_assign_trait(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (member, field_ty) = newtype_field(input, "AddAssignFromDeref")?;
+
+    // `__Rhs` cannot collide with the type's own generic parameters
+    let mut generics = input.generics.clone();
+    generics.params.push(syn::parse_quote!(__Rhs));
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_ty: ::core::ops::AddAssign<__Rhs>));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::ops::AddAssign<__Rhs> for #name #ty_generics #where_clause {
+            #[inline]
+            fn add_assign(&mut self, rhs: __Rhs) {
+                self.#member += rhs;
+            }
+        }
+    })
+}
+
+/// Implementation function for the `Iterator` forwarding derive, through the field marked with `#[deref]`
+pub fn impl_iterator_trait(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let Data::Struct(DataStruct { fields, .. }) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "IteratorFromDeref can only be used on structs",
+        ));
+    };
+
+    let mut options = DerefOptions::default();
+    options.parse_attrs(&input.attrs)?;
+    let deref_field = find_deref_field(&input.ident, fields, &mut options)?;
+    let member = &deref_field.member;
+    let field_ty = &deref_field.ty;
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_ty: ::core::iter::Iterator));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::iter::Iterator for #name #ty_generics #where_clause {
+            type Item = <#field_ty as ::core::iter::Iterator>::Item;
+
+            #[inline]
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                ::core::iter::Iterator::next(&mut self.#member)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                ::core::iter::Iterator::size_hint(&self.#member)
+            }
+        }
+    })
+}
+
+/// Implementation function for the `Index` and `IndexMut` forwarding derives
+///
+/// `IndexMut` requires `Index`, so the mutable derive implements both.
+pub fn impl_index_trait(input: &DeriveInput, is_mut: bool) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let trait_name = if is_mut { "IndexMut" } else { "Index" };
+    let Data::Struct(DataStruct { fields, .. }) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            format!("{} can only be used on structs", trait_name),
+        ));
+    };
+
+    let mut options = DerefOptions::default();
+    options.parse_attrs(&input.attrs)?;
+    let deref_field = find_deref_field(&input.ident, fields, &mut options)?;
+    let member = &deref_field.member;
+    let field_ty = &deref_field.ty;
+
+    // `__Idx` cannot collide with the type's own generic parameters
+    let mut generics = input.generics.clone();
+    generics.params.push(syn::parse_quote!(__Idx));
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_ty: ::core::ops::Index<__Idx>));
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let index_impl = {
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics ::core::ops::Index<__Idx> for #name #ty_generics #where_clause {
+                type Output = <#field_ty as ::core::ops::Index<__Idx>>::Output;
+
+                #[inline]
+                fn index(&self, index: __Idx) -> &Self::Output {
+                    ::core::ops::Index::index(&self.#member, index)
+                }
+            }
+        }
+    };
+
+    let index_mut_impl = is_mut.then(|| {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#field_ty: ::core::ops::IndexMut<__Idx>));
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics ::core::ops::IndexMut<__Idx> for #name #ty_generics #where_clause {
+                #[inline]
+                fn index_mut(&mut self, index: __Idx) -> &mut Self::Output {
+                    ::core::ops::IndexMut::index_mut(&mut self.#member, index)
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #index_impl
+        #index_mut_impl
+    })
+}
+
+/// Implementation function for the `EqInner` derive, comparing the field with a raw value
+///
+/// The reverse impl, `FieldType == Name`, is left out when the field type is a bare type parameter
+/// (or a reference to one), since the orphan rules reject `impl<T> PartialEq<Name<T>> for T`.
+pub fn impl_eq_inner(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let Data::Struct(DataStruct { fields, .. }) = &input.data else {
+        return Err(syn::Error::new_spanned(input, "EqInner can only be used on structs"));
+    };
+
+    let mut options = DerefOptions::default();
+    options.parse_attrs(&input.attrs)?;
+    let deref_field = find_deref_field(&input.ident, fields, &mut options)?;
+    let member = &deref_field.member;
+    let field_ty = &deref_field.ty;
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_ty: ::core::cmp::PartialEq));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let reverse = (!mentions_type_param(quote!(#field_ty), input)).then(|| {
+        quote! {
+            impl #impl_generics ::core::cmp::PartialEq<#name #ty_generics> for #field_ty #where_clause {
+                #[inline]
+                fn eq(&self, other: &#name #ty_generics) -> bool {
+                    ::core::cmp::PartialEq::eq(self, &other.#member)
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::core::cmp::PartialEq<#field_ty> for #name #ty_generics #where_clause {
+            #[inline]
+            fn eq(&self, other: &#field_ty) -> bool {
+                ::core::cmp::PartialEq::eq(&self.#member, other)
+            }
+        }
+
+        #reverse
+    })
+}
+
+/// Whether a type mentions one of the type parameters of `input` anywhere, as in `T`, `&T` or
+/// `Box<T>`, where the orphan rules may forbid implementing a trait for it
+fn mentions_type_param(tokens: TokenStream2, input: &DeriveInput) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Group(group) => mentions_type_param(group.stream(), input),
+        TokenTree::Ident(ident) => input.generics.type_params().any(|param| param.ident == ident),
+        _ => false,
+    })
+}
+
+/// Function to get the only field of a newtype struct
+fn newtype_field<'a>(input: &'a DeriveInput, trait_name: &str) -> syn::Result<(Member, &'a Type)> {
+    let error = || {
+        syn::Error::new_spanned(
+            input,
+            format!("{} can only be used on structs with a single field", trait_name),
+        )
+    };
+
+    let Data::Struct(DataStruct { fields, .. }) = &input.data else {
+        return Err(error());
+    };
+    let field = match fields {
+        Fields::Named(_) | Fields::Unnamed(_) if fields.len() == 1 => fields.iter().next().ok_or_else(error)?,
+        _ => return Err(error()),
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+
+    Ok((member, &field.ty))
+}
@@ -0,0 +1,53 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+#[deref(must_use_accessor)]
+pub struct Token(#[deref] String);
+
+#[derive(DerefMut)]
+struct Counter {
+    #[deref(must_use_accessor)]
+    count: u32,
+}
+
+#[test]
+fn must_use_accessor_returns_target() {
+    let token = Token("secret".to_string());
+    assert_eq!(token.get(), "secret");
+}
+
+#[test]
+fn must_use_accessor_has_mutable_variant() {
+    let mut counter = Counter { count: 1 };
+    *counter.get_mut() += 1;
+    assert_eq!(*counter.get(), 2);
+}
+
+#[derive(Deref)]
+#[deref(const)]
+struct Capacity(#[deref] usize);
+
+#[derive(DerefMut)]
+#[deref(const, must_use_accessor)]
+struct Origin {
+    #[deref]
+    point: (i32, i32),
+}
+
+const CAPACITY: Capacity = Capacity(4);
+const SLOTS: [u8; *CAPACITY.get()] = [0; *CAPACITY.get()];
+
+const fn shifted() -> Origin {
+    let mut origin = Origin { point: (0, 0) };
+    origin.get_mut().0 = 3;
+    origin
+}
+
+#[test]
+fn const_accessor_in_const_contexts() {
+    assert_eq!(SLOTS.len(), 4);
+    assert_eq!(*CAPACITY, 4);
+
+    const ORIGIN: Origin = shifted();
+    assert_eq!(*ORIGIN.get(), (3, 0));
+}
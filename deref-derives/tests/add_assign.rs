@@ -0,0 +1,40 @@
+use deref_derives::{AddAssignFromDeref, DerefMut};
+
+#[derive(DerefMut, AddAssignFromDeref)]
+struct Counter {
+    #[deref]
+    count: u32,
+}
+
+#[derive(DerefMut, AddAssignFromDeref)]
+struct Hello(#[deref] String);
+
+#[derive(AddAssignFromDeref)]
+struct Total<T>(T);
+
+#[test]
+fn add_assign_matches_deref_mut() {
+    let mut counter = Counter { count: 1 };
+    counter += 1;
+    *counter += 1;
+    counter += &2;
+
+    assert_eq!(*counter, 5);
+}
+
+#[test]
+fn add_assign_with_other_rhs_types() {
+    let mut hello = Hello("Hello".to_string());
+    hello += ", world";
+    *hello += "!";
+
+    assert_eq!(hello.as_str(), "Hello, world!");
+}
+
+#[test]
+fn add_assign_on_generic_newtype() {
+    let mut total = Total(1.5f64);
+    total += 2.0;
+
+    assert_eq!(total.0, 3.5);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use deref_derives::{ArbitraryFromDeref, Deref};
+
+#[derive(ArbitraryFromDeref, Deref)]
+struct Port(u16);
+
+#[derive(ArbitraryFromDeref, Deref)]
+struct Tagged<T> {
+    #[deref]
+    value: T,
+    label: String,
+}
+
+#[derive(ArbitraryFromDeref)]
+struct Name<'a>(&'a str);
+
+#[test]
+fn wrapper_from_arbitrary_field() {
+    let mut input = Unstructured::new(&[0x34, 0x12]);
+    let port = Port::arbitrary(&mut input).unwrap();
+
+    assert_eq!(*port, 0x1234);
+}
+
+#[test]
+fn marked_field_with_defaulted_siblings() {
+    let bytes = [7; 32];
+    let mut input = Unstructured::new(&bytes);
+    let tagged = Tagged::<[u8; 4]>::arbitrary(&mut input).unwrap();
+
+    assert_eq!(*tagged, [7; 4]);
+    assert!(tagged.label.is_empty());
+}
+
+#[test]
+fn borrowed_field_takes_rest() {
+    let name = Name::arbitrary_take_rest(Unstructured::new(b"fuzz")).unwrap();
+
+    assert_eq!(name.0, "fuzz");
+}
+
+#[test]
+fn size_hint_matches_field() {
+    assert_eq!(Port::size_hint(0), u16::size_hint(0));
+}
@@ -0,0 +1,50 @@
+use deref_derives::{AsMut, DerefMut};
+
+#[derive(AsMut)]
+struct Slot<T> {
+    #[deref_mut]
+    value: T,
+    generation: u32,
+}
+
+#[derive(AsMut)]
+struct Counter(u64);
+
+#[derive(DerefMut, AsMut)]
+struct Buffer {
+    #[deref_mut]
+    bytes: Vec<u8>,
+    dirty: bool,
+}
+
+fn reset<T: AsMut<U>, U: Default>(value: &mut T) {
+    *value.as_mut() = U::default();
+}
+
+#[test]
+fn generic_struct() {
+    let mut slot = Slot {
+        value: "full".to_string(),
+        generation: 2,
+    };
+    reset::<_, String>(&mut slot);
+    assert_eq!((slot.value.as_str(), slot.generation), ("", 2));
+}
+
+#[test]
+fn tuple_struct() {
+    let mut counter = Counter(5);
+    *counter.as_mut() += 1;
+    assert_eq!(counter.0, 6);
+}
+
+#[test]
+fn coexists_with_deref_mut() {
+    let mut buffer = Buffer {
+        bytes: vec![1],
+        dirty: false,
+    };
+    buffer.push(2);
+    buffer.as_mut().push(3);
+    assert_eq!((buffer.bytes.as_slice(), buffer.dirty), (&[1, 2, 3][..], false));
+}
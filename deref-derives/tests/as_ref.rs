@@ -0,0 +1,93 @@
+use deref_derives::{AsRef, Deref};
+use std::path::{Path, PathBuf};
+
+#[derive(AsRef)]
+struct Config {
+    #[deref]
+    path: PathBuf,
+    readonly: bool,
+}
+
+#[derive(AsRef)]
+struct Label(String);
+
+#[derive(AsRef)]
+struct Tagged<T> {
+    #[auto_ref]
+    value: T,
+    tag: u8,
+}
+
+#[derive(Deref, AsRef)]
+struct Bytes(Vec<u8>);
+
+#[derive(Deref, AsRef)]
+struct Document {
+    title: String,
+    #[deref]
+    body: String,
+}
+
+#[derive(Deref, AsRef)]
+struct Entry {
+    #[deref]
+    key: String,
+    #[as_ref]
+    path: PathBuf,
+}
+
+fn borrowed<T: AsRef<U>, U: ?Sized>(value: &T) -> &U {
+    value.as_ref()
+}
+
+#[test]
+fn named_field() {
+    let config = Config {
+        path: PathBuf::from("/etc"),
+        readonly: true,
+    };
+    let path: &PathBuf = config.as_ref();
+    assert_eq!(path.as_path(), Path::new("/etc"));
+    assert!(config.readonly);
+}
+
+#[test]
+fn tuple_field() {
+    let label = Label("name".to_string());
+    assert_eq!(borrowed::<_, String>(&label), "name");
+}
+
+#[test]
+fn generic_field() {
+    let tagged = Tagged { value: 3u32, tag: 1 };
+    assert_eq!(*borrowed::<_, u32>(&tagged), 3);
+    assert_eq!(tagged.tag, 1);
+}
+
+#[test]
+fn coexists_with_deref() {
+    let bytes = Bytes(vec![1, 2]);
+    assert_eq!(bytes.len(), 2);
+    assert_eq!(borrowed::<_, Vec<u8>>(&bytes), &[1, 2]);
+}
+
+#[test]
+fn shares_deref_marker() {
+    let document = Document {
+        title: "title".to_string(),
+        body: "body text".to_string(),
+    };
+    assert_eq!(document.len(), 9);
+    assert_eq!(borrowed::<_, String>(&document), "body text");
+    assert_eq!(document.title, "title");
+}
+
+#[test]
+fn as_ref_marker_takes_precedence() {
+    let entry = Entry {
+        key: "key".to_string(),
+        path: PathBuf::from("/tmp"),
+    };
+    assert_eq!(entry.len(), 3);
+    assert_eq!(borrowed::<_, PathBuf>(&entry), Path::new("/tmp"));
+}
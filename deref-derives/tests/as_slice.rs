@@ -0,0 +1,44 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Item(u32);
+
+#[derive(Deref)]
+struct Fixed<const N: usize>(#[deref(as_slice)] [Item; N]);
+
+#[derive(DerefMut)]
+struct FixedMut<const N: usize>(#[deref(as_slice)] [Item; N]);
+
+#[derive(Deref)]
+struct Buffer<T> {
+    #[deref(as_slice)]
+    items: Vec<T>,
+}
+
+#[test]
+fn array_field_derefs_to_slice() {
+    let fixed = Fixed([Item(1), Item(2), Item(3)]);
+
+    let slice: &[Item] = &fixed;
+    assert_eq!(slice.len(), 3);
+    assert_eq!(fixed[1], Item(2));
+    assert_eq!(fixed.iter().map(|item| item.0).sum::<u32>(), 6);
+    assert_eq!(&fixed[1..], &[Item(2), Item(3)]);
+}
+
+#[test]
+fn array_field_derefs_to_mutable_slice() {
+    let mut fixed = FixedMut([Item(3), Item(1), Item(2)]);
+
+    fixed[0] = Item(4);
+    fixed.sort_by_key(|item| item.0);
+    assert_eq!(*fixed, [Item(1), Item(2), Item(4)]);
+}
+
+#[test]
+fn vec_field_derefs_to_slice() {
+    let buffer = Buffer { items: vec!["a", "b"] };
+
+    assert_eq!(buffer.first(), Some(&"a"));
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), ["a", "b"]);
+}
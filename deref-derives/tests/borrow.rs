@@ -0,0 +1,36 @@
+use deref_derives::{Borrow, BorrowMut, Deref};
+use std::borrow::{Borrow, BorrowMut};
+use std::collections::HashMap;
+
+#[derive(Deref, Borrow, Hash, PartialEq, Eq)]
+struct UserId(u64);
+
+#[derive(BorrowMut)]
+struct Scratch<T> {
+    #[deref_mut]
+    buffer: T,
+    uses: u32,
+}
+
+#[test]
+fn map_keyed_by_wrapper_is_queried_with_the_inner_type() {
+    let mut names = HashMap::new();
+    names.insert(UserId(1), "ada");
+    names.insert(UserId(2), "grace");
+
+    assert_eq!(names.get(&2), Some(&"grace"));
+    assert!(names.contains_key(&1));
+    assert_eq!(names.remove(&1), Some("ada"));
+    assert!(names.keys().all(|id| **id == 2));
+}
+
+#[test]
+fn borrow_mut_implements_both_traits() {
+    let mut scratch = Scratch {
+        buffer: vec![1],
+        uses: 0,
+    };
+    BorrowMut::<Vec<i32>>::borrow_mut(&mut scratch).push(2);
+    let buffer: &Vec<i32> = scratch.borrow();
+    assert_eq!((buffer.as_slice(), scratch.uses), (&[1, 2][..], 0));
+}
@@ -0,0 +1,50 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::thread;
+
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+#[deref(bound = "T: Send")]
+struct Job<T> {
+    payload: T,
+}
+
+#[derive(DerefMut)]
+#[deref(bound = "T: Debug", bound = "U: Clone")]
+struct Pair<T, U> {
+    #[deref]
+    left: T,
+    right: U,
+}
+
+#[derive(Deref)]
+#[deref(bound = "T: Send + Sync, T: 'static")]
+struct Shared<T>(Arc<T>);
+
+fn target_of<J: std::ops::Deref<Target = T>, T: Send>(job: &J) -> &T {
+    job
+}
+
+#[test]
+fn single_bound() {
+    let job = Job { payload: vec![1, 2] };
+    assert_eq!(target_of(&job).len(), 2);
+}
+
+#[test]
+fn repeated_bounds() {
+    let mut pair = Pair {
+        left: 1,
+        right: "r".to_string(),
+    };
+    *pair += 1;
+    assert_eq!((*pair, pair.right.as_str()), (2, "r"));
+}
+
+#[test]
+fn several_predicates_in_one_bound() {
+    let shared = Shared(Arc::new(5));
+    let value = Arc::clone(&shared);
+    assert_eq!(thread::spawn(move || *value).join().unwrap(), 5);
+}
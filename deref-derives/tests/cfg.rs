@@ -0,0 +1,84 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+#[deref(cfg(all(test, debug_assertions)), doc_cfg)]
+struct DebugOnly(#[deref] u32);
+
+#[derive(DerefMut)]
+#[deref(cfg(not(any())), must_use_accessor)]
+struct Enabled {
+    #[deref]
+    value: String,
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn gated_impl_is_active() {
+    assert_eq!(*DebugOnly(3), 3);
+}
+
+#[test]
+fn gate_applies_to_every_generated_item() {
+    let mut enabled = Enabled {
+        value: "on".to_string(),
+    };
+    enabled.get_mut().push('!');
+    assert_eq!(enabled.get(), "on!");
+}
+
+// `doc_cfg` only takes effect under `--cfg docsrs`, so expand a fixture crate with and without it
+#[test]
+fn doc_cfg_is_emitted_for_docsrs() {
+    let expand = |docsrs: bool| {
+        let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR"));
+        let source = dir.join("doc_cfg_fixture.rs");
+        std::fs::write(
+            &source,
+            "#![cfg_attr(docsrs, feature(doc_cfg))]\n\
+             #[derive(deref_derives::Deref)]\n\
+             #[deref(cfg(not(feature = \"extra\")), doc_cfg)]\n\
+             pub struct Meters(f64);\n",
+        )
+        .unwrap();
+
+        let mut rustc = std::process::Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()));
+        rustc
+            .env("RUSTC_BOOTSTRAP", "1")
+            .args([
+                "--edition",
+                "2024",
+                "--crate-type",
+                "lib",
+                "-Zunpretty=expanded",
+                "--extern",
+            ])
+            .arg(format!("deref_derives={}", proc_macro_path().display()))
+            .arg(&source);
+        if docsrs {
+            rustc.args(["--cfg", "docsrs"]);
+        }
+
+        let output = rustc.output().unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    assert!(expand(true).contains("#[doc(cfg(not(feature = \"extra\")))]"));
+    assert!(!expand(false).contains("#[doc(cfg("));
+}
+
+/// The most recently built `deref_derives` proc-macro, next to this test binary
+fn proc_macro_path() -> std::path::PathBuf {
+    let deps = std::env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let prefix = format!("{}deref_derives-", std::env::consts::DLL_PREFIX);
+    std::fs::read_dir(deps)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(std::env::consts::DLL_SUFFIX))
+        })
+        .max_by_key(|path| path.metadata().and_then(|metadata| metadata.modified()).ok())
+        .expect("deref_derives proc-macro next to the test binary")
+}
@@ -0,0 +1,43 @@
+use std::cell::Cell;
+
+use deref_derives::Deref;
+
+/// Side state that is deliberately not `Clone`
+#[derive(Default)]
+struct Stats {
+    hits: Cell<u32>,
+}
+
+#[derive(Deref)]
+#[deref(clone_field_only)]
+struct Cached {
+    #[deref]
+    value: String,
+    stats: Stats,
+}
+
+#[derive(Deref)]
+#[deref(clone_field_only)]
+struct Tagged<T>(Vec<u8>, #[deref] T);
+
+#[test]
+fn clone_copies_the_field_and_defaults_the_rest() {
+    let cached = Cached {
+        value: "hot".to_string(),
+        stats: Stats { hits: Cell::new(3) },
+    };
+    let copy = cached.clone();
+
+    assert_eq!(*copy, "hot");
+    assert_eq!(copy.stats.hits.get(), 0);
+    assert_eq!(cached.stats.hits.get(), 3);
+}
+
+#[test]
+fn clone_generic_tuple_field() {
+    let tagged = Tagged(vec![1, 2], 'x');
+    let copy = tagged.clone();
+
+    assert_eq!(*copy, 'x');
+    assert!(copy.0.is_empty());
+}
@@ -0,0 +1,28 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+#[deref(constructor = "new")]
+pub struct Meters(#[deref] f64);
+
+#[derive(DerefMut)]
+#[deref(constructor = "with_items", constructor_vis = "pub(crate)")]
+pub struct Queue<T> {
+    #[deref]
+    items: Vec<T>,
+    pops: usize,
+}
+
+#[test]
+fn constructor_wraps_the_field() {
+    let meters = Meters::new(2.5);
+    assert_eq!(*meters, 2.5);
+}
+
+#[test]
+fn constructor_defaults_other_fields() {
+    let mut queue = Queue::with_items(vec![1, 2]);
+    queue.push(3);
+
+    assert_eq!(*queue, [1, 2, 3]);
+    assert_eq!(queue.pops, 0);
+}
@@ -0,0 +1,46 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+#[deref(debug_wrapped)]
+struct UserId(u64);
+
+#[derive(DerefMut)]
+#[deref(debug_wrapped)]
+struct Session<T> {
+    #[deref]
+    token: T,
+    secret: [u8; 4],
+}
+
+#[derive(Deref)]
+#[deref(debug_wrapped)]
+enum Name {
+    First(String),
+    Last(String),
+}
+
+#[test]
+fn prints_the_type_name_and_inner_value() {
+    assert_eq!(format!("{:?}", UserId(7)), "UserId(7)");
+}
+
+#[test]
+fn named_fields_are_printed_in_tuple_style() {
+    let session = Session {
+        token: "abc",
+        secret: [0; 4],
+    };
+    let debug = format!("{:?}", session);
+    assert_eq!(debug, "Session(\"abc\")");
+    assert!(!debug.contains("secret") && session.secret.len() == 4);
+}
+
+#[test]
+fn alternate_format_and_enums() {
+    assert_eq!(format!("{:#?}", UserId(1)), "UserId(\n    1,\n)");
+    assert_eq!(
+        format!("{:?}", Name::Last("Lovelace".to_string())),
+        "Name(\"Lovelace\")"
+    );
+    assert_eq!(Name::First("Ada".to_string()).len(), 3);
+}
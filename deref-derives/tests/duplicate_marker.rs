@@ -0,0 +1,33 @@
+use deref_derives::Deref;
+
+// Appends a marked bookkeeping field, like a code generator unaware of the existing marker
+macro_rules! with_generation {
+    ($(#[$attr:meta])* struct $name:ident { $($fields:tt)* }) => {
+        $(#[$attr])*
+        struct $name {
+            $($fields)*
+            #[deref]
+            generation: u64,
+        }
+    };
+}
+
+with_generation! {
+    #[derive(Deref)]
+    #[deref(allow_duplicate_marker)]
+    struct Document {
+        #[deref]
+        text: String,
+    }
+}
+
+#[test]
+fn first_marked_field_is_chosen() {
+    let document = Document {
+        text: "body".to_string(),
+        generation: 2,
+    };
+
+    assert_eq!(document.as_str(), "body");
+    assert_eq!(document.generation, 2);
+}
@@ -0,0 +1,67 @@
+use deref_derives::{Deref, EqInner};
+
+#[derive(Deref, EqInner)]
+struct Name(String);
+
+#[derive(EqInner)]
+struct Reading {
+    #[deref]
+    celsius: f64,
+    sensor: &'static str,
+}
+
+#[derive(EqInner)]
+struct Label<'a>(&'a str);
+
+#[derive(EqInner)]
+struct Wrapped<T>(T);
+
+#[derive(EqInner)]
+struct Items<T>(Vec<T>);
+
+#[derive(EqInner)]
+struct Boxed<T>(Box<T>);
+
+#[test]
+fn wrapper_against_inner() {
+    let name = Name("hello".to_string());
+
+    assert!(name == "hello".to_string());
+    assert!(name != "world".to_string());
+    assert_eq!(name.len(), 5);
+}
+
+#[test]
+fn inner_against_wrapper() {
+    let name = Name("hello".to_string());
+
+    assert!("hello".to_string() == name);
+    assert!("world".to_string() != name);
+}
+
+#[test]
+fn marked_field() {
+    let reading = Reading {
+        celsius: 21.5,
+        sensor: "hall",
+    };
+
+    assert!(reading == 21.5);
+    assert!(21.5 == reading);
+    assert_eq!(reading.sensor, "hall");
+}
+
+#[test]
+fn borrowed_field() {
+    let label = Label("tag");
+
+    assert!(label == "tag");
+    assert!("tag" == label);
+}
+
+#[test]
+fn generic_fields() {
+    assert!(Wrapped(3) == 3);
+    assert!(Items(vec![1, 2]) == vec![1, 2]);
+    assert!(Boxed(Box::new('b')) == Box::new('b'));
+}
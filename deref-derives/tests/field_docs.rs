@@ -0,0 +1,34 @@
+//! Accessors repeat the doc comments of the deref field, which `missing_docs` checks here
+#![deny(missing_docs)]
+
+use deref_derives::{Deref, DerefMut};
+
+/// A token
+#[derive(Deref)]
+#[deref(must_use_accessor)]
+pub struct Token {
+    /// The raw token text
+    #[deref]
+    inner: String,
+}
+
+/// A counter
+#[derive(DerefMut)]
+#[deref(const)]
+pub struct Counter(
+    /// The current count
+    #[deref]
+    u32,
+);
+
+#[test]
+fn documented_accessors() {
+    let token = Token {
+        inner: "docs".to_string(),
+    };
+    assert_eq!(token.get(), "docs");
+
+    let mut counter = Counter(1);
+    *counter.get_mut() += 1;
+    assert_eq!(*counter.get(), 2);
+}
@@ -0,0 +1,139 @@
+use std::marker::PhantomData;
+
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+#[deref(field = "type")]
+struct Token {
+    r#type: String,
+    line: usize,
+}
+
+#[derive(DerefMut)]
+#[deref(field = "r#match")]
+struct Pattern {
+    r#match: Vec<char>,
+}
+
+#[derive(Deref)]
+#[deref(field = "1")]
+struct Labeled(&'static str, u32);
+
+#[test]
+fn keyword_field_selected_by_string() {
+    let token = Token {
+        r#type: "ident".to_string(),
+        line: 1,
+    };
+    assert_eq!(token.as_str(), "ident");
+    assert_eq!(token.line, 1);
+}
+
+#[test]
+fn raw_identifier_spelling_is_accepted() {
+    let mut pattern = Pattern { r#match: vec!['a'] };
+    pattern.push('b');
+    assert_eq!(pattern.len(), 2);
+}
+
+#[test]
+fn tuple_field_selected_by_index() {
+    let labeled = Labeled("answer", 42);
+    assert_eq!(*labeled, 42);
+    assert_eq!(labeled.0, "answer");
+}
+
+#[derive(Deref, Debug, Clone, Default, PartialEq)]
+#[allow(dead_code)]
+#[repr(C)]
+#[must_use]
+#[doc = "deref"]
+#[cfg_attr(all(), derive(Eq))]
+#[deref(field = "inner")]
+struct Annotated {
+    id: u32,
+    inner: String,
+}
+
+#[test]
+fn unrelated_container_attributes_are_ignored() {
+    let annotated = Annotated {
+        id: 1,
+        inner: "robust".to_string(),
+    };
+
+    assert_eq!(annotated.len(), 6);
+    assert_eq!(annotated.clone(), annotated);
+}
+
+#[derive(Deref)]
+#[deref(field = "value")]
+struct Tied<'a, T> {
+    value: T,
+    _marker: PhantomData<&'a ()>,
+}
+
+fn tie<T>(value: T, _scope: &str) -> Tied<'_, T> {
+    Tied {
+        value,
+        _marker: PhantomData,
+    }
+}
+
+#[test]
+fn phantom_lifetime_stays_on_the_impl() {
+    let scope = String::from("scope");
+    let tied = tie(vec![1, 2], &scope);
+
+    assert_eq!(tied.len(), 2);
+}
+
+struct State {
+    inner: Vec<u8>,
+    version: u32,
+}
+
+#[derive(DerefMut)]
+#[deref(field = "state.inner", target = "Vec<u8>")]
+struct Session {
+    state: State,
+    id: u64,
+}
+
+struct Config {
+    name: String,
+}
+
+#[derive(Deref)]
+#[deref(field = "layers.0.name", target = "str")]
+struct Stack {
+    layers: (Config, u8),
+}
+
+#[test]
+fn two_level_field_path() {
+    let mut session = Session {
+        state: State {
+            inner: vec![1],
+            version: 2,
+        },
+        id: 3,
+    };
+    session.push(4);
+    assert_eq!(*session, [1, 4]);
+    assert_eq!((session.state.version, session.id), (2, 3));
+}
+
+#[test]
+fn three_level_field_path() {
+    let stack = Stack {
+        layers: (
+            Config {
+                name: "base".to_string(),
+            },
+            1,
+        ),
+    };
+    assert_eq!(&*stack, "base");
+    assert_eq!(stack.layers.1, 1);
+}
@@ -0,0 +1,45 @@
+use deref_derives::{Deref, DerefMut};
+
+fn identity(value: &u8) -> &u8 {
+    value
+}
+
+fn longest<'a>(left: &'a str, right: &'a str) -> &'a str {
+    if right.len() > left.len() { right } else { left }
+}
+
+#[derive(Deref)]
+struct Callback(for<'a> fn(&'a u8) -> &'a u8);
+
+#[derive(DerefMut)]
+struct Picker {
+    #[deref]
+    pick: for<'a> fn(&'a str, &'a str) -> &'a str,
+    calls: usize,
+}
+
+#[test]
+fn higher_ranked_fn_pointer_field() {
+    let callback = Callback(identity);
+    let value = 9;
+
+    assert_eq!(*callback(&value), 9);
+}
+
+#[test]
+fn replace_higher_ranked_fn_pointer() {
+    fn shortest<'a>(left: &'a str, right: &'a str) -> &'a str {
+        if right.len() < left.len() { right } else { left }
+    }
+
+    let mut picker = Picker {
+        pick: longest,
+        calls: 0,
+    };
+    assert_eq!((*picker)("ab", "abc"), "abc");
+
+    *picker = shortest;
+    picker.calls += 1;
+    assert_eq!((*picker)("ab", "abc"), "ab");
+    assert_eq!(picker.calls, 1);
+}
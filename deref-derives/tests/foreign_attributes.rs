@@ -0,0 +1,36 @@
+use deref_derives::{Deref, DerefMut};
+use deref_test_helper::Describe;
+
+#[derive(Deref, Describe)]
+struct User {
+    #[deref]
+    #[describe = "display name"]
+    name: String,
+    #[describe = "primary key"]
+    id: u64,
+}
+
+#[derive(Describe, DerefMut)]
+struct Settings {
+    #[describe = "raw values"]
+    #[deref_mut]
+    values: Vec<u8>,
+}
+
+#[test]
+fn both_derives_read_their_own_field_attributes() {
+    let user = User {
+        name: "ada".to_string(),
+        id: 7,
+    };
+    assert_eq!((user.len(), user.id), (3, 7));
+    assert_eq!(User::descriptions(), [("name", "display name"), ("id", "primary key")]);
+}
+
+#[test]
+fn attribute_order_does_not_matter() {
+    let mut settings = Settings { values: vec![] };
+    settings.push(1);
+    assert_eq!(*settings, [1]);
+    assert_eq!(Settings::descriptions(), [("values", "raw values")]);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "forward")]
+
+use deref_derives::{Deref, Forward};
+use std::io::{Cursor, Read, Write};
+
+#[derive(Deref, Forward)]
+#[forward(trait = "Read")]
+struct Input<R> {
+    #[deref]
+    reader: R,
+    name: &'static str,
+}
+
+#[derive(Forward)]
+#[forward(trait = "Read", trait = "Write")]
+struct Buffer(Cursor<Vec<u8>>);
+
+#[derive(Forward)]
+#[forward(trait = "Iterator")]
+struct Lines<'a> {
+    lines: std::str::Lines<'a>,
+}
+
+#[test]
+fn forwards_read_to_the_inner_reader() {
+    let mut input = Input {
+        reader: &b"hello"[..],
+        name: "stdin",
+    };
+    let mut text = String::new();
+    input.read_to_string(&mut text).unwrap();
+    assert_eq!((text.as_str(), input.name), ("hello", "stdin"));
+    assert!(input.is_empty());
+}
+
+#[test]
+fn forwards_several_traits() {
+    let mut buffer = Buffer(Cursor::new(Vec::new()));
+    buffer.write_all(b"abc").unwrap();
+    buffer.flush().unwrap();
+    buffer.0.set_position(0);
+
+    let mut bytes = [0; 2];
+    buffer.read_exact(&mut bytes).unwrap();
+    assert_eq!(&bytes, b"ab");
+}
+
+#[test]
+fn forwards_iterator() {
+    let lines = Lines { lines: "a\nb".lines() };
+    assert_eq!(lines.collect::<Vec<_>>(), ["a", "b"]);
+}
@@ -0,0 +1,141 @@
+use std::marker::PhantomData;
+
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+struct Conv<T, U>
+where
+    T: Into<U>,
+{
+    #[deref]
+    t: T,
+    _pd: PhantomData<U>,
+}
+
+impl<T: Into<U>, U> Conv<T, U> {
+    fn convert(self) -> U {
+        self.t.into()
+    }
+}
+
+#[test]
+fn cross_referencing_where_clause() {
+    let conv: Conv<u8, u32> = Conv { t: 7, _pd: PhantomData };
+
+    assert_eq!(*conv, 7u8);
+    assert_eq!(conv.convert(), 7u32);
+}
+
+struct Inner<T = u8> {
+    value: T,
+}
+
+#[derive(Deref)]
+struct Outer {
+    #[deref]
+    inner: Inner,
+}
+
+#[test]
+fn defaulted_type_param_target() {
+    let outer = Outer {
+        inner: Inner { value: 3 },
+    };
+
+    let inner: &Inner<u8> = &outer;
+    assert_eq!(inner.value, 3u8);
+}
+
+type MyRef<'a, T> = &'a [T];
+
+#[derive(Deref)]
+struct Window<'a, T> {
+    #[deref]
+    items: MyRef<'a, T>,
+}
+
+#[test]
+fn parameterized_type_alias_target() {
+    let values = [1, 2, 3];
+    let window = Window { items: &values[1..] };
+
+    let items: &MyRef<'_, i32> = &window;
+    assert_eq!(items.len(), 2);
+    assert_eq!(window[0], 2);
+}
+
+#[derive(DerefMut)]
+struct Cursor<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    #[deref]
+    iter: I,
+}
+
+impl<I> Cursor<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    fn next_twice(&mut self) -> Option<(I::Item, I::Item)> {
+        let item = self.next()?;
+        Some((item.clone(), item))
+    }
+}
+
+#[test]
+fn associated_type_where_clause() {
+    let mut cursor = Cursor {
+        iter: vec!["a", "b", "c"].into_iter(),
+    };
+
+    assert_eq!(cursor.next(), Some("a"));
+    assert_eq!(cursor.next_twice(), Some(("b", "b")));
+    assert_eq!(cursor.len(), 1);
+}
+
+#[derive(Deref)]
+struct Matrix<T, const R: usize, const C: usize> {
+    #[deref]
+    data: [[T; C]; R],
+}
+
+#[test]
+fn mixed_type_and_const_generics() {
+    let matrix = Matrix {
+        data: [[1u8, 2, 3], [4, 5, 6]],
+    };
+
+    assert_eq!(matrix.len(), 2);
+    assert_eq!(matrix[1], [4, 5, 6]);
+    assert_eq!(matrix.data[0][2], 3);
+}
+
+struct Handle {
+    id: u32,
+}
+
+#[derive(Deref)]
+struct Invariant<T> {
+    #[deref]
+    inner: Handle,
+    _variance: PhantomData<fn(T) -> T>,
+}
+
+fn id_of<T>(value: &Invariant<T>) -> u32 {
+    value.id
+}
+
+#[test]
+fn variance_marker_does_not_interfere() {
+    let invariant: Invariant<String> = Invariant {
+        inner: Handle { id: 9 },
+        _variance: PhantomData,
+    };
+    let inner: &Handle = &invariant;
+
+    assert_eq!(inner.id, 9);
+    assert_eq!(id_of(&invariant), 9);
+}
@@ -0,0 +1,43 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+enum Id {
+    User(u64),
+    Post(u64),
+}
+
+#[derive(DerefMut)]
+enum Message {
+    Text(String),
+    Status { body: String },
+}
+
+#[derive(Deref)]
+#[deref(target = "str")]
+enum Name {
+    Short(String),
+    Long(String),
+}
+
+#[test]
+fn derefs_to_the_field_of_every_variant() {
+    assert_eq!(*Id::User(7), 7);
+    assert_eq!(*Id::Post(9), 9);
+}
+
+#[test]
+fn deref_mut_reaches_named_and_unnamed_fields() {
+    let mut text = Message::Text("hi".to_string());
+    text.push('!');
+    assert_eq!(text.as_str(), "hi!");
+
+    let mut status = Message::Status { body: "ok".to_string() };
+    status.push_str(" now");
+    assert!(matches!(status, Message::Status { ref body } if body == "ok now"));
+}
+
+#[test]
+fn options_apply_to_every_variant() {
+    assert_eq!(&*Name::Short("a".to_string()), "a");
+    assert_eq!(Name::Long("abc".to_string()).len(), 3);
+}
@@ -0,0 +1,46 @@
+use deref_derives::{Index, IndexMut};
+use std::collections::HashMap;
+
+#[derive(IndexMut)]
+struct Samples {
+    #[deref_mut]
+    values: Vec<i32>,
+    rate: u32,
+}
+
+#[derive(Index)]
+struct Settings(HashMap<String, i32>);
+
+#[derive(Index)]
+struct Labeled<'a, T> {
+    #[deref]
+    items: Vec<T>,
+    label: &'a str,
+}
+
+#[test]
+fn indexes_a_vec_by_usize() {
+    let mut samples = Samples {
+        values: vec![1, 2, 3],
+        rate: 44_100,
+    };
+    samples[0] = 10;
+    assert_eq!(samples[0], 10);
+    assert_eq!(&samples[1..], [2, 3]);
+    assert_eq!(samples.rate, 44_100);
+}
+
+#[test]
+fn indexes_a_map_by_str() {
+    let settings = Settings(HashMap::from([("depth".to_string(), 3)]));
+    assert_eq!(settings["depth"], 3);
+}
+
+#[test]
+fn indexes_through_generics_and_lifetimes() {
+    let labeled = Labeled {
+        items: vec!['a', 'b'],
+        label: "letters",
+    };
+    assert_eq!((labeled[1], labeled.label), ('b', "letters"));
+}
@@ -0,0 +1,49 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(DerefMut)]
+#[deref(inner_api)]
+struct Buffer {
+    #[deref(target = "[u8]")]
+    bytes: Vec<u8>,
+    label: String,
+}
+
+#[derive(Deref)]
+#[deref(target = "str", inner_api(as_inner = "name", into_inner = "take"))]
+struct Name(String);
+
+#[derive(DerefMut)]
+#[deref(inner_api)]
+enum Slot {
+    Empty(Option<u32>),
+    Reserved(Option<u32>),
+}
+
+#[test]
+fn returns_the_field_rather_than_the_target() {
+    let mut buffer = Buffer {
+        bytes: vec![1, 2],
+        label: "data".to_string(),
+    };
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(buffer.label, "data");
+
+    buffer.as_inner_mut().push(3);
+    assert!(buffer.as_inner().capacity() >= 3);
+    assert_eq!(buffer.into_inner(), vec![1, 2, 3]);
+}
+
+#[test]
+fn methods_can_be_renamed() {
+    let name = Name("ada".to_string());
+    assert!(name.name().capacity() >= 3);
+    assert_eq!(name.take(), "ada");
+}
+
+#[test]
+fn works_on_every_variant() {
+    let mut slot = Slot::Reserved(None);
+    *slot.as_inner_mut() = Some(4);
+    assert!(matches!(slot, Slot::Reserved(Some(4))));
+    assert_eq!(Slot::Empty(None).into_inner(), None);
+}
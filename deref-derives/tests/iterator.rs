@@ -0,0 +1,33 @@
+use deref_derives::{DerefMut, IteratorFromDeref};
+
+#[derive(IteratorFromDeref)]
+struct Numbers {
+    #[deref]
+    iter: std::vec::IntoIter<u32>,
+    label: &'static str,
+}
+
+#[derive(DerefMut, IteratorFromDeref)]
+struct Chars<I>(u8, #[deref] I);
+
+#[test]
+fn iterator_delegates_to_the_field() {
+    let numbers = Numbers {
+        iter: vec![1, 2, 3].into_iter(),
+        label: "numbers",
+    };
+
+    assert_eq!(numbers.label, "numbers");
+    assert_eq!(numbers.size_hint(), (3, Some(3)));
+    assert_eq!(numbers.map(|n| n * 2).collect::<Vec<_>>(), [2, 4, 6]);
+}
+
+#[test]
+fn iterator_with_deref_mut_on_generic_field() {
+    let mut chars = Chars(3, "abc".chars());
+    assert_eq!(chars.0, 3);
+
+    assert_eq!(chars.next(), Some('a'));
+    assert_eq!(chars.as_str(), "bc");
+    assert_eq!(chars.collect::<String>(), "bc");
+}
@@ -0,0 +1,109 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+struct Pair<'a, 'b> {
+    #[deref(lifetime = "'a")]
+    first: &'a str,
+    second: &'b str,
+}
+
+#[derive(DerefMut)]
+struct Slot<'a> {
+    #[deref(lifetime = "'a")]
+    value: &'a mut Vec<u8>,
+}
+
+#[test]
+fn derefs_to_the_referent_of_the_chosen_lifetime() {
+    let first = String::from("first");
+    let pair = {
+        let second = "second";
+        Pair { first: &first, second }
+    };
+
+    let target: &str = &pair;
+    assert_eq!(target, "first");
+    assert_eq!(pair.len(), 5);
+    assert_eq!(pair.second, "second");
+}
+
+#[test]
+fn mutable_reference_field() {
+    let mut bytes = vec![1];
+    let mut slot = Slot { value: &mut bytes };
+    slot.push(2);
+    assert_eq!(*slot, [1, 2]);
+}
+
+// The impl only type-checks if it repeats the struct's `'a: 'b` predicate
+#[derive(Deref)]
+struct Outlives<'a, 'b, T>
+where
+    'a: 'b,
+{
+    #[deref]
+    long: &'a T,
+    short: &'b T,
+}
+
+#[test]
+fn lifetime_outlives_where_clause() {
+    let long = 1u8;
+    {
+        let short = 2u8;
+        let outlives = Outlives {
+            long: &long,
+            short: &short,
+        };
+
+        assert_eq!(**outlives, 1);
+        assert_eq!(*outlives.short, 2);
+    }
+}
+
+#[derive(Deref)]
+struct Borrowed<'a, T> {
+    #[deref]
+    value: &'a T,
+}
+
+#[derive(Deref)]
+struct Referent<'a, T> {
+    #[deref(through)]
+    value: &'a T,
+}
+
+#[test]
+fn reference_field_targets_the_reference() {
+    let value = 3u8;
+    let borrowed = Borrowed { value: &value };
+    let target: &&u8 = &borrowed;
+
+    assert_eq!(**target, 3);
+    assert_eq!(borrowed.pow(2), 9);
+}
+
+#[test]
+fn through_reference_field_targets_the_referent() {
+    let value = 3u8;
+    let referent = Referent { value: &value };
+    let target: &u8 = &referent;
+
+    assert_eq!(*target, 3);
+}
+
+#[derive(Deref)]
+struct Restated<'a> {
+    #[deref(target = "&'a str")]
+    inner: &'a str,
+}
+
+#[test]
+fn target_restating_the_field_lifetime() {
+    let text = String::from("restated");
+    let restated = Restated { inner: &text };
+    let target: &&str = &restated;
+
+    assert_eq!(*target, "restated");
+    assert_eq!(restated.len(), 8);
+}
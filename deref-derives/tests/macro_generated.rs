@@ -0,0 +1,42 @@
+use deref_derives::{Deref, DerefMut};
+
+macro_rules! wrapper {
+    ($name:ident, $inner:ty) => {
+        #[derive(Deref)]
+        struct $name {
+            #[deref]
+            inner: $inner,
+            label: &'static str,
+        }
+    };
+}
+
+macro_rules! wrapper_mut {
+    ($name:ident, $marker:ident, $inner:ty) => {
+        #[derive(DerefMut)]
+        struct $name(u8, #[$marker] $inner);
+    };
+}
+
+wrapper!(Name, String);
+wrapper_mut!(Counts, auto_ref, Vec<u32>);
+
+#[test]
+fn marker_inside_macro_rules_struct() {
+    let name = Name {
+        inner: "hygiene".to_string(),
+        label: "name",
+    };
+
+    assert_eq!(name.len(), 7);
+    assert_eq!(name.label, "name");
+}
+
+#[test]
+fn marker_passed_as_macro_argument() {
+    let mut counts = Counts(0, vec![1]);
+    counts.push(2);
+
+    assert_eq!(*counts, [1, 2]);
+    assert_eq!(counts.0, 0);
+}
@@ -0,0 +1,99 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+struct Named {
+    id: u32,
+    #[auto_ref]
+    name: String,
+}
+
+#[derive(Deref)]
+struct Tuple(u32, #[auto_ref] Vec<u8>);
+
+#[derive(Deref)]
+struct Titled {
+    #[auto_ref(target = "str")]
+    title: String,
+}
+
+#[derive(DerefMut)]
+struct Buffer {
+    #[deref_mut]
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+#[test]
+fn auto_ref_on_named_field() {
+    let named = Named {
+        id: 1,
+        name: "alias".to_string(),
+    };
+    assert_eq!(named.len(), 5);
+    assert_eq!(named.id, 1);
+}
+
+#[test]
+fn auto_ref_on_tuple_field() {
+    let tuple = Tuple(2, vec![1, 2, 3]);
+    assert_eq!(tuple.len(), 3);
+    assert_eq!(tuple.0, 2);
+}
+
+#[test]
+fn auto_ref_options() {
+    let titled = Titled {
+        title: "options".to_string(),
+    };
+    let title: &str = &titled;
+    assert_eq!(title, "options");
+}
+
+#[test]
+fn deref_mut_marker() {
+    let mut buffer = Buffer { bytes: vec![], len: 0 };
+    buffer.push(1);
+    buffer.len = buffer.bytes.len();
+
+    assert_eq!(*buffer, [1]);
+    assert_eq!(buffer.len, 1);
+}
+
+#[derive(DerefMut)]
+struct Boxed {
+    #[deref_mut(through)]
+    value: Box<u32>,
+    edits: u32,
+}
+
+#[derive(DerefMut)]
+struct Shared {
+    #[deref]
+    #[deref_mut]
+    value: String,
+    hits: u32,
+}
+
+#[test]
+fn deref_and_deref_mut_on_the_same_field() {
+    let mut shared = Shared {
+        value: "a".to_string(),
+        hits: 0,
+    };
+    shared.push('b');
+    assert_eq!((shared.as_str(), shared.hits), ("ab", 0));
+}
+
+#[test]
+fn deref_mut_options() {
+    // `through` makes the boxed `u32` the target of both traits
+    let deref: fn(&Boxed) -> &u32 = std::ops::Deref::deref;
+    let deref_mut: fn(&mut Boxed) -> &mut u32 = std::ops::DerefMut::deref_mut;
+
+    let mut boxed = Boxed {
+        value: Box::new(1),
+        edits: 0,
+    };
+    *deref_mut(&mut boxed) += 1;
+    assert_eq!((*deref(&boxed), boxed.edits), (2, 0));
+}
@@ -0,0 +1,35 @@
+use deref_derives::DerefMut;
+
+#[derive(DerefMut)]
+#[deref(modify_method = "modify")]
+struct Query {
+    #[deref]
+    terms: Vec<&'static str>,
+    limit: usize,
+}
+
+#[derive(DerefMut)]
+#[deref(target = "str", modify_method = "edit")]
+struct Title(String);
+
+#[test]
+fn chained_modify_calls() {
+    let query = Query {
+        terms: vec!["deref"],
+        limit: 10,
+    }
+    .modify(|terms| terms.push("rust"))
+    .modify(|terms| terms.retain(|term| term.len() == 4));
+
+    assert_eq!(*query, ["rust"]);
+    assert_eq!(query.limit, 10);
+}
+
+#[test]
+fn modify_through_target() {
+    let title = Title("deref".to_string())
+        .edit(|title| title.make_ascii_uppercase())
+        .edit(|title| title[..1].make_ascii_lowercase());
+
+    assert_eq!(&*title, "dEREF");
+}
@@ -0,0 +1,24 @@
+use deref_derives::{Deref, NegFromDeref};
+
+#[derive(Deref, NegFromDeref, Debug, Clone, Copy, PartialEq)]
+struct Meters(#[deref] f64);
+
+#[derive(NegFromDeref, Debug, PartialEq)]
+struct Offset<T> {
+    delta: T,
+}
+
+#[test]
+fn neg_rewraps_the_field() {
+    let distance = Meters(3.5);
+    let negated = -distance;
+
+    assert_eq!(negated, Meters(-3.5));
+    assert_eq!(*negated, -3.5);
+    assert_eq!(-negated, distance);
+}
+
+#[test]
+fn neg_on_generic_named_field() {
+    assert_eq!(-Offset { delta: 4i32 }, Offset { delta: -4 });
+}
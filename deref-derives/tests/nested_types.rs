@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use deref_derives::{Deref, DerefMut};
+
+struct Inner {
+    hits: u32,
+}
+
+#[derive(Deref)]
+struct Registry {
+    #[deref]
+    entries: HashMap<String, Vec<Arc<Mutex<Inner>>>>,
+}
+
+#[derive(DerefMut)]
+#[allow(clippy::type_complexity)]
+struct Nested<'a, T: Clone = u8> {
+    #[deref]
+    levels: Vec<Option<Box<[&'a [(T, Result<T, String>)]]>>>,
+}
+
+#[test]
+fn deeply_nested_field_type() {
+    let inner = Arc::new(Mutex::new(Inner { hits: 1 }));
+    let registry = Registry {
+        entries: HashMap::from([("a".to_string(), vec![inner.clone(), inner])]),
+    };
+
+    assert_eq!(registry["a"].len(), 2);
+    assert_eq!(registry["a"][0].lock().unwrap().hits, 1);
+}
+
+#[test]
+fn nested_references_and_defaults() {
+    let pairs = [(1u8, Ok(2)), (3, Err("e".to_string()))];
+    let mut nested: Nested = Nested { levels: Vec::new() };
+    nested.push(Some(Box::new([&pairs[..]])));
+
+    assert_eq!(nested[0].as_ref().unwrap()[0][1].0, 3);
+}
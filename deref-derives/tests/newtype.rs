@@ -0,0 +1,69 @@
+use std::ops::Deref;
+
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+struct Wrapper<T>(#[deref] T);
+
+#[derive(Deref)]
+struct Bounded<T: Sized>(#[deref] T);
+
+#[derive(DerefMut)]
+struct Named<T>
+where
+    T: Sized,
+{
+    #[deref]
+    value: T,
+}
+
+#[derive(Deref)]
+struct Through<T: Deref>(#[deref(through)] T);
+
+#[test]
+fn bare_generic_tuple_newtype() {
+    let wrapper = Wrapper(String::from("bare"));
+    assert_eq!(wrapper.len(), 4);
+
+    let number = Wrapper(7u8);
+    assert_eq!(number.pow(2), 49);
+}
+
+#[test]
+fn explicit_sized_bound() {
+    let sized = Bounded(vec![1, 2, 3]);
+    assert_eq!(sized.iter().sum::<i32>(), 6);
+}
+
+#[test]
+fn bare_generic_named_newtype() {
+    let mut named = Named { value: Vec::new() };
+    named.push('a');
+
+    assert_eq!(named.len(), 1);
+    assert_eq!(named.value, ['a']);
+}
+
+#[test]
+fn deref_coercion_to_the_generic_type() {
+    struct Point {
+        x: i32,
+    }
+
+    fn x(point: &Point) -> i32 {
+        point.x
+    }
+
+    let wrapper = Wrapper(Point { x: 6 });
+    assert_eq!(x(&wrapper), 6);
+}
+
+#[test]
+fn through_generic_deref() {
+    let through = Through(Box::new(3u32));
+    assert_eq!(through.leading_zeros(), 30);
+
+    let through = Through(String::from("str"));
+    let target: &str = &through;
+    assert_eq!(target, "str");
+}
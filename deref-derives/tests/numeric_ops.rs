@@ -0,0 +1,37 @@
+#![cfg(feature = "numeric_ops")]
+
+use deref_derives::{AddFromDeref, Deref, DivFromDeref, MulFromDeref, SubFromDeref};
+
+#[derive(Deref, AddFromDeref, SubFromDeref, MulFromDeref, DivFromDeref, Debug, Clone, Copy, PartialEq)]
+struct Meters(#[deref] f64);
+
+#[derive(AddFromDeref, SubFromDeref, Debug, PartialEq)]
+struct Count<T> {
+    value: T,
+}
+
+#[test]
+fn add_rewraps() {
+    assert_eq!(Meters(1.5) + Meters(2.0), Meters(3.5));
+}
+
+#[test]
+fn sub_rewraps() {
+    assert_eq!(Meters(5.0) - Meters(1.5), Meters(3.5));
+}
+
+#[test]
+fn mul_rewraps() {
+    assert_eq!(*(Meters(1.5) * Meters(2.0)), 3.0);
+}
+
+#[test]
+fn div_rewraps() {
+    assert_eq!(Meters(3.0) / Meters(2.0), Meters(1.5));
+}
+
+#[test]
+fn generic_named_field() {
+    assert_eq!(Count { value: 3 } + Count { value: 4 }, Count { value: 7 });
+    assert_eq!(Count { value: 3u8 } - Count { value: 1 }, Count { value: 2 });
+}
@@ -0,0 +1,48 @@
+use deref_derives::Deref;
+use std::borrow::{Borrow, Cow};
+use std::path::{Path, PathBuf};
+
+#[derive(Deref, Debug, PartialEq)]
+#[repr(transparent)]
+#[deref(from_ref, owned = "PathBufWrapper")]
+struct PathWrapper(Path);
+
+#[derive(Deref, Debug, PartialEq)]
+struct PathBufWrapper(PathBuf);
+
+impl From<PathBuf> for PathBufWrapper {
+    fn from(path: PathBuf) -> Self {
+        PathBufWrapper(path)
+    }
+}
+
+impl Borrow<PathWrapper> for PathBufWrapper {
+    fn borrow(&self) -> &PathWrapper {
+        PathWrapper::from_ref(&self.0)
+    }
+}
+
+fn normalize(path: &PathWrapper) -> Cow<'_, PathWrapper> {
+    if path.is_absolute() {
+        Cow::Borrowed(path)
+    } else {
+        Cow::Owned(PathBufWrapper(Path::new("/").join(&path.0)))
+    }
+}
+
+#[test]
+fn to_owned_builds_the_owned_wrapper() {
+    let borrowed = PathWrapper::from_ref(Path::new("/tmp"));
+    let owned = borrowed.to_owned();
+    assert_eq!(owned, PathBufWrapper(PathBuf::from("/tmp")));
+}
+
+#[test]
+fn borrowed_newtype_works_with_cow() {
+    let absolute = normalize(PathWrapper::from_ref(Path::new("/etc")));
+    assert!(matches!(absolute, Cow::Borrowed(_)));
+
+    let relative = normalize(PathWrapper::from_ref(Path::new("etc")));
+    assert_eq!(&*relative, PathWrapper::from_ref(Path::new("/etc")));
+    assert_eq!(relative.into_owned().0, PathBuf::from("/etc"));
+}
@@ -0,0 +1,68 @@
+//! Generated impls must stay clean for downstream crates denying `clippy::pedantic`.
+//! `cargo clippy --all-targets` checks this file; the test itself only exercises the items.
+#![deny(clippy::pedantic)]
+
+use std::marker::PhantomData;
+
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Debug)]
+pub struct EmptyError;
+
+fn non_empty(value: &str) -> Result<(), EmptyError> {
+    if value.is_empty() { Err(EmptyError) } else { Ok(()) }
+}
+
+#[derive(DerefMut)]
+#[deref(validate = "non_empty", error = "EmptyError", must_use_accessor)]
+#[deref(replace_method = "replace", swap_method = "swap")]
+pub struct Name(#[deref] String);
+
+#[derive(DerefMut)]
+#[repr(transparent)]
+#[deref(from_ref)]
+pub struct Wrapped<T>(#[deref] Vec<T>, PhantomData<T>);
+
+#[derive(DerefMut)]
+pub struct Boxed<'a> {
+    #[deref(through)]
+    value: Box<[u8]>,
+    #[allow(dead_code)]
+    marker: PhantomData<&'a ()>,
+}
+
+#[derive(DerefMut)]
+pub struct Slice<const N: usize>(#[deref(as_slice)] [u8; N]);
+
+#[derive(DerefMut)]
+pub struct Borrowed<'a> {
+    #[deref(lifetime = "'a")]
+    value: &'a mut String,
+}
+
+#[derive(Deref)]
+pub enum Single {
+    Only(u64),
+}
+
+#[test]
+fn generated_code_is_pedantic_clean() {
+    let mut name = Name::try_from("deref".to_string()).unwrap();
+    assert_eq!(name.replace("rs".to_string()), "deref");
+    assert_eq!(name.get(), "rs");
+
+    let vec = vec![1];
+    assert_eq!(Wrapped::<i32>::from_ref(&vec).len(), 1);
+
+    let boxed = Boxed {
+        value: Box::new([1, 2]),
+        marker: PhantomData,
+    };
+    assert_eq!(boxed.len(), 2);
+    assert_eq!(Slice([0; 3]).len(), 3);
+
+    let mut value = String::new();
+    let mut borrowed = Borrowed { value: &mut value };
+    borrowed.push('a');
+    assert_eq!(*Single::Only(4), 4);
+}
@@ -0,0 +1,54 @@
+use deref_derives::{Deref, DerefMut};
+
+struct Limits {
+    max: u32,
+}
+
+struct Settings {
+    limits: Limits,
+    name: String,
+}
+
+struct Config {
+    settings: Settings,
+    version: u8,
+}
+
+#[derive(Deref)]
+struct App {
+    #[deref(project = "settings.limits", target = "Limits")]
+    config: Config,
+    running: bool,
+}
+
+#[derive(DerefMut)]
+#[deref(project = "name", target = "String")]
+struct Named(Settings);
+
+#[test]
+fn projects_two_levels_below_the_marked_field() {
+    let app = App {
+        config: Config {
+            settings: Settings {
+                limits: Limits { max: 8 },
+                name: "app".to_string(),
+            },
+            version: 1,
+        },
+        running: true,
+    };
+    assert_eq!(app.max, 8);
+    assert_eq!(app.config.settings.name, "app");
+    assert!(app.running && app.config.version == 1);
+}
+
+#[test]
+fn projects_below_the_sole_field() {
+    let mut named = Named(Settings {
+        limits: Limits { max: 1 },
+        name: "a".to_string(),
+    });
+    named.push('b');
+    assert_eq!(named.0.name, "ab");
+    assert_eq!(named.0.limits.max, 1);
+}
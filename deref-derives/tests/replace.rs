@@ -0,0 +1,43 @@
+use deref_derives::DerefMut;
+
+#[derive(DerefMut)]
+#[deref(replace_method = "replace", swap_method = "swap")]
+struct Slot<T> {
+    #[deref]
+    value: T,
+    generation: u32,
+}
+
+#[derive(DerefMut)]
+struct Buffer(#[deref(replace_method = "take_replace")] Vec<u8>);
+
+#[test]
+fn replace_returns_the_previous_value() {
+    let mut slot = Slot {
+        value: "old".to_string(),
+        generation: 1,
+    };
+
+    assert_eq!(slot.replace("new".to_string()), "old");
+    assert_eq!(slot.as_str(), "new");
+    assert_eq!(slot.generation, 1);
+}
+
+#[test]
+fn swap_exchanges_values() {
+    let mut slot = Slot {
+        value: 1,
+        generation: 0,
+    };
+    let mut other = 2;
+
+    slot.swap(&mut other);
+    assert_eq!((*slot, other), (2, 1));
+}
+
+#[test]
+fn custom_method_name() {
+    let mut buffer = Buffer(vec![1, 2]);
+    assert_eq!(buffer.take_replace(Vec::new()), [1, 2]);
+    assert!(buffer.is_empty());
+}
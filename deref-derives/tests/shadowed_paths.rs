@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+// Local items named like the standard crates and prelude types must not capture generated paths
+mod std {}
+mod core {}
+
+type Result<T> = ::core::result::Result<T, &'static str>;
+
+struct Option;
+struct Default;
+struct Clone;
+
+use deref_derives::{AddAssignFromDeref, DerefMut, IteratorFromDeref, NegFromDeref};
+
+fn non_empty(value: &str) -> Result<()> {
+    if value.is_empty() { Err("empty") } else { Ok(()) }
+}
+
+#[derive(DerefMut)]
+#[deref(
+    replace_method = "replace",
+    swap_method = "swap",
+    clone_field_only,
+    constructor = "new",
+    validate = "non_empty",
+    error = "&'static str"
+)]
+struct Name {
+    #[deref]
+    value: String,
+    hits: u32,
+}
+
+#[derive(DerefMut, NegFromDeref, AddAssignFromDeref)]
+struct Meters(f64);
+
+#[derive(IteratorFromDeref)]
+struct Numbers(::std::vec::IntoIter<u8>);
+
+#[test]
+fn generated_paths_ignore_local_shadowing() {
+    let mut name = Name::try_from("a".to_string()).unwrap();
+    assert_eq!(name.replace("b".to_string()), "a");
+    let mut other = "c".to_string();
+    name.swap(&mut other);
+    assert_eq!(name.clone().as_str(), "c");
+    assert_eq!(Name::new("d".to_string()).hits, 0);
+
+    let mut meters = -Meters(1.0);
+    meters += 3.0;
+    assert_eq!(*meters, 2.0);
+
+    assert_eq!(Numbers(vec![1, 2].into_iter()).count(), 2);
+}
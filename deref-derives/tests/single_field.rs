@@ -0,0 +1,24 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+struct Meters(f64);
+
+#[derive(DerefMut)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[test]
+fn single_tuple_field_without_marker() {
+    let meters = Meters(1.5);
+    assert!(meters.is_sign_positive());
+}
+
+#[test]
+fn single_named_field_without_marker() {
+    let mut wrapper = Wrapper { inner: Vec::new() };
+    wrapper.push(1);
+
+    assert_eq!(*wrapper, [1]);
+    assert_eq!(wrapper.inner.len(), 1);
+}
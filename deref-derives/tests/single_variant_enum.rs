@@ -0,0 +1,54 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+enum Newtype {
+    V(String),
+}
+
+#[derive(DerefMut)]
+#[deref(replace_method = "replace")]
+enum Labeled<T> {
+    Value {
+        label: &'static str,
+        #[deref]
+        value: T,
+    },
+}
+
+#[derive(Deref)]
+#[deref(validate = "non_empty", error = "()")]
+enum Checked {
+    Items(#[deref(as_slice)] Vec<u8>, u32),
+}
+
+fn non_empty(items: &[u8]) -> Result<(), ()> {
+    if items.is_empty() { Err(()) } else { Ok(()) }
+}
+
+#[test]
+fn derefs_to_the_sole_variant_field() {
+    let newtype = Newtype::V("hello".to_string());
+    assert_eq!(newtype.len(), 5);
+    assert_eq!(newtype.as_str(), "hello");
+}
+
+#[test]
+fn marked_field_in_named_variant() {
+    let mut labeled = Labeled::Value {
+        label: "count",
+        value: 1,
+    };
+    *labeled += 1;
+    assert_eq!(labeled.replace(10), 2);
+
+    let Labeled::Value { label, value } = labeled;
+    assert_eq!((label, value), ("count", 10));
+}
+
+#[test]
+fn options_apply_to_variant_fields() {
+    let checked = Checked::try_from(vec![1, 2]).unwrap();
+    assert_eq!(checked.len(), 2);
+    assert!(matches!(checked, Checked::Items(_, 0)));
+    assert!(Checked::try_from(Vec::new()).is_err());
+}
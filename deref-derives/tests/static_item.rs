@@ -0,0 +1,36 @@
+use deref_derives::Deref;
+
+struct Settings {
+    name: &'static str,
+    retries: u32,
+}
+
+static CONFIG: Settings = Settings {
+    name: "global",
+    retries: 3,
+};
+
+#[derive(Deref)]
+#[deref(static = "CONFIG", target = "Settings")]
+struct GlobalConfig;
+
+mod limits {
+    pub static MAX: [u16; 3] = [10, 20, 30];
+}
+
+#[derive(Deref)]
+#[deref(static = "limits::MAX", target = "[u16]")]
+struct Limits;
+
+#[test]
+fn unit_struct_derefs_to_static() {
+    assert_eq!(GlobalConfig.name, "global");
+    assert_eq!(GlobalConfig.retries, 3);
+    assert!(std::ptr::eq(&*GlobalConfig, &CONFIG));
+}
+
+#[test]
+fn static_path_with_coerced_target() {
+    assert_eq!(Limits.len(), 3);
+    assert_eq!(Limits[2], 30);
+}
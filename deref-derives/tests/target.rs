@@ -0,0 +1,99 @@
+// Target mismatch notes are `deprecated` warnings, none of the targets below may trigger one
+#![deny(deprecated)]
+
+use std::rc::Rc;
+
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+struct Name {
+    #[deref(target = "str")]
+    value: String,
+}
+
+#[derive(DerefMut)]
+#[deref(target = "[T]")]
+struct Stack<T> {
+    #[deref]
+    items: Vec<T>,
+    pushes: usize,
+}
+
+#[derive(Deref)]
+struct Label(#[deref(target = "str")] Rc<String>);
+
+#[derive(Deref)]
+struct Predicate {
+    #[deref(target = "dyn for<'x> Fn(&'x u8) -> bool")]
+    check: Box<dyn Fn(&u8) -> bool>,
+}
+
+#[derive(Deref)]
+#[deref(target = "for<'x> fn(&'x str) -> &'x str")]
+struct Trim<'a> {
+    #[deref]
+    func: for<'x> fn(&'x str) -> &'x str,
+    input: &'a str,
+}
+
+type MyU32 = u32;
+
+#[derive(Deref)]
+struct Count {
+    #[deref(target = "MyU32")]
+    value: u32,
+}
+
+#[test]
+fn string_field_to_str_target() {
+    let name = Name {
+        value: "target".to_string(),
+    };
+    let target: &str = &name;
+
+    assert_eq!(target, "target");
+}
+
+#[test]
+fn vec_field_to_slice_target() {
+    let mut stack = Stack {
+        items: vec![3, 1, 2],
+        pushes: 3,
+    };
+    stack.sort_unstable();
+    let target: &[i32] = &stack;
+
+    assert_eq!(target, [1, 2, 3]);
+    assert_eq!(stack.pushes, 3);
+}
+
+#[test]
+fn target_through_several_derefs() {
+    let label = Label(Rc::new("shared".to_string()));
+    let target: &str = &label;
+
+    assert_eq!(target, "shared");
+}
+
+#[test]
+fn higher_ranked_target_lifetimes() {
+    let predicate = Predicate {
+        check: Box::new(|value| *value > 1),
+    };
+    assert!(predicate(&2));
+    assert!(!predicate(&1));
+
+    let trim = Trim {
+        func: str::trim,
+        input: " padded ",
+    };
+    assert_eq!((*trim)(trim.input), "padded");
+}
+
+#[test]
+fn alias_target_is_not_a_mismatch() {
+    let count = Count { value: 3 };
+    let value: &MyU32 = &count;
+
+    assert_eq!(*value, 3);
+}
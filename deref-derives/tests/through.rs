@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+struct Shared {
+    #[deref(through)]
+    config: Arc<String>,
+}
+
+#[derive(Deref)]
+struct Local<T>(#[deref(through)] Rc<T>);
+
+#[derive(DerefMut)]
+struct Owned {
+    #[deref(through)]
+    value: Box<[u8; 2]>,
+}
+
+#[test]
+fn derefs_through_shared_pointers() {
+    let shared = Shared {
+        config: Arc::new("config".to_string()),
+    };
+    let target: &String = &shared;
+    assert_eq!(target, "config");
+
+    let local = Local(Rc::new(5));
+    assert_eq!(*local, 5);
+}
+
+#[test]
+fn derefs_mutably_through_box() {
+    let mut owned = Owned {
+        value: Box::new([2, 1]),
+    };
+    owned.sort();
+    assert_eq!(*owned, [1, 2]);
+}
+
+#[derive(Deref)]
+struct Bytes<'a> {
+    #[deref(through)]
+    data: Cow<'a, [u8]>,
+}
+
+#[test]
+fn through_borrowed_cow() {
+    let data = [3, 1, 2];
+    let bytes = Bytes {
+        data: Cow::Borrowed(&data),
+    };
+    let slice: &[u8] = &bytes;
+
+    assert_eq!(slice, [3, 1, 2]);
+    assert_eq!(bytes.iter().max(), Some(&3));
+}
+
+#[test]
+fn through_owned_cow() {
+    let bytes = Bytes {
+        data: Cow::Owned(vec![1, 2]),
+    };
+
+    assert_eq!(bytes.len(), 2);
+    assert!(bytes.starts_with(&[1]));
+}
+
+#[derive(Deref)]
+struct Name {
+    #[deref(through)]
+    s: Box<str>,
+}
+
+#[derive(DerefMut)]
+struct Frame(#[deref(through)] Box<[u8]>);
+
+#[test]
+fn through_boxed_str() {
+    let name = Name { s: "interned".into() };
+    let target: &str = &name;
+
+    assert_eq!(target, "interned");
+    assert!(name.ends_with("ned"));
+}
+
+#[test]
+fn through_boxed_slice() {
+    let mut frame = Frame(vec![3, 2, 1].into_boxed_slice());
+    frame.reverse();
+    let target: &[u8] = &frame;
+
+    assert_eq!(target, [1, 2, 3]);
+    assert_eq!(frame.first(), Some(&1));
+}
+
+struct Inner {
+    depth: u8,
+}
+
+#[derive(DerefMut)]
+struct Outer {
+    #[deref(forward)]
+    inner: Box<Inner>,
+}
+
+#[derive(Deref)]
+struct Counted(#[deref(forward)] Rc<Inner>);
+
+#[derive(DerefMut)]
+struct Items<T>(#[deref(forward)] Vec<T>);
+
+#[test]
+fn forward_box() {
+    let mut outer = Outer {
+        inner: Box::new(Inner { depth: 1 }),
+    };
+    outer.depth += 1;
+    let inner: &Inner = &outer;
+
+    assert_eq!(inner.depth, 2);
+}
+
+#[test]
+fn forward_rc() {
+    let counted = Counted(Rc::new(Inner { depth: 3 }));
+    assert_eq!(counted.depth, 3);
+}
+
+#[test]
+fn forward_vec_to_slice() {
+    let mut items = Items(vec!['b', 'a']);
+    items.sort_unstable();
+    let slice: &[char] = &items;
+
+    assert_eq!(slice, ['a', 'b']);
+}
+
+#[derive(Deref)]
+struct Report(#[deref(through)] Box<dyn Debug + Send + Sync>);
+
+#[derive(DerefMut)]
+struct Job {
+    #[deref(through)]
+    run: Box<dyn FnMut() -> u32 + Send>,
+}
+
+fn describe(value: &(dyn Debug + Send + Sync)) -> String {
+    format!("{:?}", value)
+}
+
+fn is_sync<T: Sync + ?Sized>(_: &T) {}
+
+#[test]
+fn through_keeps_every_trait_object_bound() {
+    let report = Report(Box::new([1, 2]));
+    is_sync(&*report);
+    assert_eq!(describe(&*report), "[1, 2]");
+}
+
+#[test]
+fn through_trait_object_with_auto_trait_mutably() {
+    let mut count = 0;
+    let mut job = Job {
+        run: Box::new(move || {
+            count += 1;
+            count
+        }),
+    };
+    job();
+    assert_eq!((*job)(), 2);
+}
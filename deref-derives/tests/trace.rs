@@ -0,0 +1,71 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+#[deref(trace)]
+struct Traced(#[deref] String);
+
+#[derive(DerefMut)]
+struct TracedMut {
+    #[deref(trace = "eprintln")]
+    values: Vec<u8>,
+}
+
+#[test]
+fn trace_returns_the_field() {
+    let traced = Traced("hello".to_string());
+    assert_eq!(traced.len(), 5);
+
+    let mut traced = TracedMut { values: vec![1] };
+    traced.push(2);
+    assert_eq!(*traced, [1, 2]);
+}
+
+mod log_backend {
+    use std::sync::Mutex;
+
+    use deref_derives::{Deref, DerefMut};
+
+    #[derive(Deref)]
+    #[deref(trace = "log")]
+    struct Traced(#[deref] String);
+
+    #[derive(DerefMut)]
+    struct TracedMut {
+        #[deref(trace = "log")]
+        values: Vec<u8>,
+    }
+
+    static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct Recorder;
+
+    impl log::Log for Recorder {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            RECORDS.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn trace_logs_only_in_debug_builds() {
+        log::set_logger(&Recorder).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let traced = Traced("hello".to_string());
+        let _ = traced.len();
+        let mut traced = TracedMut { values: vec![] };
+        traced.push(1);
+
+        let records = RECORDS.lock().unwrap();
+        if cfg!(debug_assertions) {
+            assert_eq!(*records, ["Traced::deref", "TracedMut::deref_mut"]);
+        } else {
+            assert!(records.is_empty());
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::marker::PhantomData;
+
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Debug, PartialEq)]
+struct Inner {
+    id: u32,
+}
+
+#[derive(Deref)]
+#[repr(transparent)]
+#[deref(from_ref)]
+struct W<T>(Inner, PhantomData<T>);
+
+#[derive(DerefMut)]
+#[repr(transparent)]
+#[deref(from_ref)]
+struct Name {
+    #[deref]
+    value: str,
+}
+
+#[test]
+fn from_ref_reinterprets_the_inner_value() {
+    let inner = Inner { id: 7 };
+    let wrapper: &W<String> = W::from_ref(&inner);
+
+    assert_eq!(wrapper.id, 7);
+    assert_eq!(**wrapper, Inner { id: 7 });
+}
+
+#[test]
+fn from_mut_on_unsized_field() {
+    let mut owned = String::from("deref");
+    let name = Name::from_mut(owned.as_mut_str());
+    name.make_ascii_uppercase();
+
+    assert_eq!(Name::from_ref("DEREF").len(), 5);
+    assert_eq!(owned, "DEREF");
+}
@@ -0,0 +1,40 @@
+use deref_derives::{Deref, DerefMut};
+
+macro_rules! bytes {
+    () => { Vec<u8> };
+    ($len:literal) => { [u8; $len] };
+}
+
+#[derive(Deref)]
+struct Packet {
+    #[deref]
+    payload: bytes!(),
+}
+
+#[derive(DerefMut)]
+struct Header(#[deref] bytes! {4});
+
+#[derive(Deref)]
+struct Boxed(#[deref(through)] Box<bytes!(2)>);
+
+#[test]
+fn type_macro_field_is_the_target() {
+    let packet = Packet { payload: vec![1, 2, 3] };
+    let payload: &Vec<u8> = &packet;
+
+    assert_eq!(payload.len(), 3);
+}
+
+#[test]
+fn type_macro_field_with_deref_mut() {
+    let mut header = Header([0; 4]);
+    header[0] = 7;
+
+    assert_eq!(*header, [7, 0, 0, 0]);
+}
+
+#[test]
+fn type_macro_inside_through_target() {
+    let boxed = Boxed(Box::new([1, 2]));
+    assert_eq!(*boxed, [1, 2]);
+}
@@ -0,0 +1,15 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Registry {
+    #[deref(as_slice)]
+    entries: HashMap<
+        String,
+        Vec<Arc<Mutex<u32>>>,
+    >,
+}
+
+fn main() {}
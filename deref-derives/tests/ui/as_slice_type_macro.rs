@@ -0,0 +1,10 @@
+use deref_derives::Deref;
+
+macro_rules! bytes {
+    ($len:literal) => { [u8; $len] };
+}
+
+#[derive(Deref)]
+struct Header(#[deref(as_slice)] bytes!(4));
+
+fn main() {}
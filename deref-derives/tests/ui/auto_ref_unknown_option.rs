@@ -0,0 +1,10 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Named {
+    id: u32,
+    #[auto_ref(targte = "str")]
+    name: String,
+}
+
+fn main() {}
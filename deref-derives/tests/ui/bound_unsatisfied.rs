@@ -0,0 +1,14 @@
+use std::rc::Rc;
+
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[deref(bound = "T: Send")]
+struct Job<T> {
+    payload: T,
+}
+
+fn main() {
+    let job = Job { payload: Rc::new(1) };
+    let _ = *job;
+}
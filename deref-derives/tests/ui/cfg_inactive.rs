@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[deref(cfg(any()))]
+struct Disabled(#[deref] u32);
+
+fn main() {
+    let _ = *Disabled(1);
+}
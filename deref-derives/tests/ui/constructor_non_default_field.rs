@@ -0,0 +1,13 @@
+use deref_derives::Deref;
+
+struct Handle;
+
+#[derive(Deref)]
+#[deref(constructor = "new")]
+struct Named {
+    #[deref]
+    name: String,
+    handle: Handle,
+}
+
+fn main() {}
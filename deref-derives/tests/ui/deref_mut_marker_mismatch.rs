@@ -0,0 +1,11 @@
+use deref_derives::DerefMut;
+
+#[derive(DerefMut)]
+struct Buffer {
+    #[deref]
+    bytes: Vec<u8>,
+    #[deref_mut]
+    len: usize,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Document {
+    #[deref]
+    text: String,
+    #[auto_ref]
+    generation: u64,
+}
+
+fn main() {}
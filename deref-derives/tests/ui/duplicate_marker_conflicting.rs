@@ -0,0 +1,12 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[deref(allow_duplicate_marker)]
+struct Document {
+    #[deref]
+    text: String,
+    #[deref(target = "str")]
+    title: String,
+}
+
+fn main() {}
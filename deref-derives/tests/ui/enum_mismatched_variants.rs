@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+enum Id {
+    User(u64),
+    Post(u32),
+}
+
+fn main() {}
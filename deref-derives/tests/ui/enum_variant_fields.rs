@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+enum Shape {
+    Circle(f64),
+    Rect(f64, f64),
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+enum Never {}
+
+fn main() {}
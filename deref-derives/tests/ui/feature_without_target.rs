@@ -0,0 +1,8 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[deref(target = "str")]
+#[deref(feature = "fast")]
+struct Name(String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[deref(field = "missing")]
+struct Wrapper {
+    inner: String,
+}
+
+fn main() {}
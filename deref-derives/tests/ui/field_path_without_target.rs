@@ -0,0 +1,13 @@
+use deref_derives::Deref;
+
+struct State {
+    inner: String,
+}
+
+#[derive(Deref)]
+#[deref(field = "state.inner")]
+struct Session {
+    state: State,
+}
+
+fn main() {}
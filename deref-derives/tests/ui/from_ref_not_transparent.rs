@@ -0,0 +1,6 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Wrapper(#[deref(from_ref)] u32, u8);
+
+fn main() {}
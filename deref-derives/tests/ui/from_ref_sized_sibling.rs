@@ -0,0 +1,9 @@
+use std::marker::PhantomData;
+
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[repr(transparent)]
+struct Wrapper(u64, #[deref(from_ref)] PhantomData<u8>);
+
+fn main() {}
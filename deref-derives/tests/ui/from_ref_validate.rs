@@ -0,0 +1,12 @@
+use deref_derives::Deref;
+
+fn check(value: &u32) -> Result<(), String> {
+    if *value > 0 { Ok(()) } else { Err("zero".to_string()) }
+}
+
+#[derive(Deref)]
+#[repr(transparent)]
+#[deref(from_ref, validate = "check", error = "String")]
+struct Positive(u32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Borrowed<'a> {
+    #[deref(lifetime = "'b")]
+    value: &'a str,
+}
+
+fn main() {}
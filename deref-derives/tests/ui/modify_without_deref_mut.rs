@@ -0,0 +1,7 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[deref(modify_method = "modify")]
+struct Count(u32);
+
+fn main() {}
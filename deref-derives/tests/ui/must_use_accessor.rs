@@ -0,0 +1,12 @@
+#![deny(unused_must_use)]
+
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[deref(must_use_accessor)]
+struct Token(#[deref] String);
+
+fn main() {
+    let token = Token("secret".to_string());
+    token.get();
+}
@@ -0,0 +1,6 @@
+use deref_derives::NegFromDeref;
+
+#[derive(NegFromDeref)]
+struct Point(f64, f64);
+
+fn main() {}
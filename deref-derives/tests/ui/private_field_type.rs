@@ -0,0 +1,8 @@
+use deref_derives::Deref;
+
+struct Secret(u8);
+
+#[derive(Deref)]
+pub struct Public(Secret);
+
+fn main() {}
@@ -0,0 +1,13 @@
+use deref_derives::Deref;
+
+struct Config {
+    name: String,
+}
+
+#[derive(Deref)]
+struct App {
+    #[deref(project = "name.as_str()", target = "str")]
+    config: Config,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[deref(replace_method = "replace")]
+struct Slot(#[deref] String);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use deref_derives::Deref;
+
+static CONFIG: u32 = 1;
+
+#[derive(Deref)]
+#[deref(static = "CONFIG", target = "u32")]
+struct Config {
+    cached: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+static CONFIG: u32 = 1;
+
+#[derive(Deref)]
+#[deref(static = "CONFIG")]
+struct Config;
+
+fn main() {}
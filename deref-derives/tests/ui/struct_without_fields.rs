@@ -0,0 +1,6 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Empty {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Wrapper {
+    #[deref(target = "&'a str")]
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Name {
+    #[deref(target = "u32")]
+    value: String,
+}
+
+fn main() {}
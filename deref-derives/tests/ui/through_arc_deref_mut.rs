@@ -0,0 +1,11 @@
+use std::sync::Arc;
+
+use deref_derives::DerefMut;
+
+#[derive(DerefMut)]
+struct Shared {
+    #[deref(through)]
+    config: Arc<String>,
+}
+
+fn main() {}
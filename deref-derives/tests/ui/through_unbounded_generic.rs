@@ -0,0 +1,6 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Through<T>(#[deref(through)] T);
+
+fn main() {}
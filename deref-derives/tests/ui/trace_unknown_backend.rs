@@ -0,0 +1,7 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+#[deref(trace = "println")]
+struct Traced(String);
+
+fn main() {}
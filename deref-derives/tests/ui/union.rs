@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+fn main() {}
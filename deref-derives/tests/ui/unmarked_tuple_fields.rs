@@ -0,0 +1,6 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Pair(u8, u16);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use deref_derives::Deref;
+
+#[derive(Deref)]
+struct Name {
+    #[deref(via = "as_ref")]
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+struct Maybe<'a, T> {
+    #[deref(unwrap)]
+    r: Option<&'a T>,
+}
+
+#[derive(Deref)]
+struct MaybeThrough<'a, T> {
+    #[deref(unwrap, through)]
+    r: Option<&'a T>,
+}
+
+#[derive(DerefMut)]
+struct Slot(#[deref(unwrap)] Option<String>);
+
+#[test]
+fn unwrap_derefs_to_the_reference() {
+    let value = 5u8;
+    let maybe = Maybe { r: Some(&value) };
+    let inner: &&u8 = &maybe;
+
+    assert_eq!(**inner, 5);
+    assert_eq!(maybe.pow(2), 25);
+}
+
+#[test]
+fn unwrap_through_derefs_to_the_referent() {
+    let text = String::from("some");
+    let maybe = MaybeThrough { r: Some(&text) };
+    let inner: &String = &maybe;
+
+    assert_eq!(inner, "some");
+    assert_eq!(maybe.len(), 4);
+}
+
+#[test]
+fn unwrap_mutably() {
+    let mut slot = Slot(Some("a".to_string()));
+    slot.push('b');
+
+    assert_eq!(slot.as_str(), "ab");
+}
+
+#[test]
+#[should_panic]
+fn unwrap_panics_on_none() {
+    let maybe: Maybe<'_, u8> = Maybe { r: None };
+    let _ = **maybe;
+}
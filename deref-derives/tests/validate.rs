@@ -0,0 +1,51 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Debug, PartialEq)]
+struct EmptyError;
+
+fn non_empty(value: &str) -> Result<(), EmptyError> {
+    if value.is_empty() { Err(EmptyError) } else { Ok(()) }
+}
+
+#[derive(Debug, DerefMut)]
+#[deref(validate = "non_empty", error = "EmptyError")]
+struct NonEmptyString(#[deref] String);
+
+mod rules {
+    pub fn positive(value: &i64) -> Result<(), String> {
+        if *value > 0 {
+            Ok(())
+        } else {
+            Err(format!("{value} is not positive"))
+        }
+    }
+}
+
+#[derive(Debug, Deref)]
+#[deref(validate = "rules::positive", error = "String")]
+struct Positive {
+    #[deref]
+    value: i64,
+    reads: usize,
+}
+
+#[test]
+fn validator_accepts_valid_values() {
+    let mut name = NonEmptyString::try_from("deref".to_string()).unwrap();
+    name.push_str("-rs");
+    assert_eq!(name.as_str(), "deref-rs");
+}
+
+#[test]
+fn validator_rejects_empty_strings() {
+    assert_eq!(NonEmptyString::try_from(String::new()).unwrap_err(), EmptyError);
+}
+
+#[test]
+fn other_fields_use_default() {
+    let positive = Positive::try_from(7).unwrap();
+    assert_eq!(*positive, 7);
+    assert_eq!(positive.reads, 0);
+
+    assert_eq!(Positive::try_from(-1).unwrap_err(), "-1 is not positive");
+}
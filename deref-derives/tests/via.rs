@@ -0,0 +1,33 @@
+use deref_derives::{Deref, DerefMut};
+
+#[derive(Deref)]
+struct Text<C> {
+    #[deref(target = "str", bound = "C: AsRef<str>", via = "as_ref")]
+    inner: C,
+}
+
+#[derive(DerefMut)]
+#[deref(target = "[u8]", bound = "B: AsRef<[u8]> + AsMut<[u8]>", via = "as_ref")]
+struct Bytes<B>(#[deref] B);
+
+#[test]
+fn via_as_ref_with_owned_field() {
+    let text = Text {
+        inner: String::from("hello"),
+    };
+    assert_eq!(&*text, "hello");
+    assert_eq!(text.len(), 5);
+}
+
+#[test]
+fn via_as_ref_with_borrowed_field() {
+    let text = Text { inner: "world" };
+    assert!(text.starts_with("wor"));
+}
+
+#[test]
+fn via_as_mut_for_deref_mut() {
+    let mut bytes = Bytes(vec![3, 1, 2]);
+    bytes.sort_unstable();
+    assert_eq!(&*bytes, [1, 2, 3]);
+}
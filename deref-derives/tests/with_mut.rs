@@ -0,0 +1,35 @@
+use deref_derives::DerefMut;
+
+#[derive(DerefMut)]
+#[deref(with_mut_method = "with_mut")]
+struct Counter<R> {
+    #[deref]
+    count: u32,
+    reason: R,
+}
+
+#[derive(DerefMut)]
+struct Items(#[deref(as_slice, with_mut_method = "edit")] Vec<i32>);
+
+#[test]
+fn with_mut_mutates_and_returns_a_value() {
+    let mut counter = Counter {
+        count: 1,
+        reason: "init",
+    };
+
+    let doubled = counter.with_mut(|count| {
+        *count *= 2;
+        *count + 1
+    });
+    assert_eq!(doubled, 3);
+    assert_eq!(*counter, 2);
+    assert_eq!(counter.reason, "init");
+}
+
+#[test]
+fn with_mut_receives_the_deref_target() {
+    let mut items = Items(vec![3, 1, 2]);
+    items.edit(<[i32]>::sort);
+    assert_eq!(*items, [1, 2, 3]);
+}
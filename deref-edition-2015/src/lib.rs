@@ -0,0 +1,57 @@
+//! Edition 2015 resolves `::name` paths and `use` paths from the crate root, so nothing here
+//! may rely on the extern prelude of later editions.
+
+#[macro_use]
+extern crate deref;
+
+use deref::{AsRef, Deref, DerefMut, Index};
+
+pub struct Meters(pub f64);
+
+deref!(Meters, f64, 0);
+
+pub struct Stack<T> {
+    pub items: Vec<T>,
+}
+
+deref!(mut <T>, Stack<T>, Vec<T>, items);
+
+#[derive(Deref)]
+pub struct Name {
+    #[deref(target = "str")]
+    pub value: String,
+    pub checked: bool,
+}
+
+#[derive(DerefMut)]
+#[deref(through, replace_method = "replace")]
+pub struct Boxed<T>(pub Box<T>);
+
+#[derive(Deref)]
+pub enum Id {
+    User(u64),
+    Post(u64),
+}
+
+#[derive(Deref, AsRef, Index)]
+#[deref(validate = "non_empty", error = "&'static str", must_use_accessor)]
+pub struct Items(pub Vec<u8>);
+
+fn non_empty(items: &[u8]) -> Result<(), &'static str> {
+    if items.is_empty() {
+        Err("empty")
+    } else {
+        Ok(())
+    }
+}
+
+pub struct Celsius(pub f64);
+pub struct Kelvin(pub f64);
+
+deref_for_each! { [Celsius, Kelvin] => f64 @ 0 }
+
+pub struct Label<'a> {
+    pub text: &'a str,
+}
+
+deref_impl!(Label<'a>, str, text);
@@ -0,0 +1,35 @@
+extern crate deref_edition_2015;
+
+use std::convert::TryFrom;
+
+use deref_edition_2015::{Boxed, Celsius, Id, Items, Kelvin, Label, Meters, Name, Stack};
+
+#[test]
+fn macros_work_in_edition_2015() {
+    assert_eq!(*Meters(1.5), 1.5);
+    assert_eq!(*Celsius(1.0) + *Kelvin(2.0), 3.0);
+    assert_eq!(Label { text: "abc" }.len(), 3);
+
+    let mut stack = Stack { items: vec![1] };
+    stack.push(2);
+    assert_eq!(stack.len(), 2);
+}
+
+#[test]
+fn derives_work_in_edition_2015() {
+    let name = Name {
+        value: "ada".to_string(),
+        checked: false,
+    };
+    assert_eq!((name.len(), name.checked), (3, false));
+
+    let mut boxed = Boxed(Box::new(1));
+    *boxed += 1;
+    assert_eq!(*boxed.replace(Box::new(5)), 2);
+    assert_eq!(*Id::Post(4), 4);
+
+    let items = Items::try_from(vec![7, 8]).unwrap();
+    assert_eq!((items[1], items.get().len()), (8, 2));
+    assert_eq!(AsRef::<Vec<u8>>::as_ref(&items).len(), 2);
+    assert!(Items::try_from(Vec::new()).is_err());
+}
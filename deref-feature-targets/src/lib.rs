@@ -0,0 +1,24 @@
+//! Wrappers whose deref target depends on the enabled features of this crate
+//!
+//! Build it with no features, `--features fast`, `--features compact` or both, where `compact`
+//! wins as it is listed last.
+
+use deref::{Deref, DerefMut};
+
+/// Derefs to `str` with `compact`, to `String` with `fast` alone and to `Box<String>` otherwise
+#[derive(Deref)]
+#[deref(feature = "fast", target = "String")]
+#[deref(feature = "compact", target = "str")]
+pub struct Name {
+    pub value: Box<String>,
+}
+
+/// Derefs to `Vec<u8>` with `fast` and to `[u8]` otherwise
+#[derive(DerefMut)]
+#[deref(target = "[u8]")]
+#[deref(feature = "fast", target = "Vec<u8>")]
+pub struct Buffer {
+    #[deref]
+    pub bytes: Vec<u8>,
+    pub label: &'static str,
+}
@@ -0,0 +1,59 @@
+use std::any::type_name;
+use std::ops::Deref;
+
+use deref_feature_targets::{Buffer, Name};
+
+fn target_of<T: Deref>(_: &T) -> &'static str {
+    type_name::<T::Target>()
+}
+
+fn name() -> Name {
+    Name {
+        value: Box::new("feature".to_string()),
+    }
+}
+
+#[test]
+#[cfg(all(feature = "fast", not(feature = "compact")))]
+fn fast_targets() {
+    assert_eq!(target_of(&name()), type_name::<String>());
+}
+
+#[test]
+#[cfg(feature = "compact")]
+fn compact_wins_over_fast() {
+    assert_eq!(target_of(&name()), type_name::<str>());
+    assert_eq!(&*name(), "feature");
+}
+
+#[test]
+#[cfg(feature = "fast")]
+fn fast_buffer_target() {
+    let mut buffer = Buffer {
+        bytes: vec![1],
+        label: "fast",
+    };
+    buffer.push(2);
+    assert_eq!(target_of(&buffer), type_name::<Vec<u8>>());
+    assert_eq!(*buffer, [1, 2]);
+}
+
+#[test]
+#[cfg(not(feature = "fast"))]
+fn default_buffer_target() {
+    let mut buffer = Buffer {
+        bytes: vec![1, 2],
+        label: "default",
+    };
+    buffer[0] = 3;
+    assert_eq!(target_of(&buffer), type_name::<[u8]>());
+    assert_eq!(*buffer, [3, 2]);
+    assert_eq!(buffer.label, "default");
+}
+
+#[test]
+#[cfg(not(any(feature = "fast", feature = "compact")))]
+fn default_name_target() {
+    assert_eq!(target_of(&name()), type_name::<Box<String>>());
+    assert_eq!(name().len(), 7);
+}
@@ -0,0 +1,33 @@
+//! Compiles the derives and declarative macros of `deref` in a `no_std` crate
+#![no_std]
+
+use deref::{Deref, DerefMut, deref};
+
+#[derive(Deref)]
+pub struct Meters(f64);
+
+#[derive(DerefMut)]
+pub struct Buffer {
+    #[deref(as_slice)]
+    bytes: [u8; 4],
+    len: usize,
+}
+
+pub struct Id {
+    value: u32,
+}
+
+deref!(Id, u32, value);
+
+pub struct Slot<T> {
+    value: T,
+}
+
+deref!(mut <T>, Slot<T>, T, value);
+
+pub fn sum(meters: &Meters, buffer: &mut Buffer, id: &Id, slot: &mut Slot<u8>) -> f64 {
+    buffer[0] = **slot;
+    **slot += 1;
+    buffer.len = buffer.bytes.len();
+    **meters + f64::from(**id) + f64::from(buffer[0])
+}
@@ -0,0 +1,53 @@
+//! A stand-in for third-party derives that read their own helper attributes on fields
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, parse_macro_input};
+
+/// Derive macro generating `fn descriptions() -> &'static [(&'static str, &'static str)]`
+///
+/// Lists the named fields carrying `#[describe = "..."]` with their descriptions.
+#[proc_macro_derive(Describe, attributes(describe))]
+pub fn derive_describe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(&input, "Describe can only be used on structs")
+            .to_compile_error()
+            .into();
+    };
+
+    let mut entries = Vec::new();
+    for field in &data.fields {
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("describe")) {
+            let description = match &attr.meta {
+                syn::Meta::NameValue(syn::MetaNameValue {
+                    value:
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(description),
+                            ..
+                        }),
+                    ..
+                }) => Ok(description),
+                _ => Err(syn::Error::new_spanned(attr, "expected #[describe = \"...\"]")),
+            };
+            match (description, &field.ident) {
+                (Ok(description), Some(ident)) => {
+                    let field_name = ident.to_string();
+                    entries.push(quote!((#field_name, #description)));
+                }
+                (Ok(_), None) => {}
+                (Err(err), _) => return err.to_compile_error().into(),
+            }
+        }
+    }
+
+    quote! {
+        impl #name {
+            pub fn descriptions() -> &'static [(&'static str, &'static str)] {
+                &[#(#entries),*]
+            }
+        }
+    }
+    .into()
+}
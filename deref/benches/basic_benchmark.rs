@@ -1,7 +1,7 @@
 use std::hint::black_box;
 
 use criterion::{Criterion, criterion_group, criterion_main};
-use deref::{deref, deref_mut};
+use deref::deref;
 
 // 手动实现 Deref 的结构体
 #[derive(Debug, Clone, Copy)]
@@ -25,13 +25,13 @@ struct MacroDeref {
 
 deref!(MacroDeref, i32, value);
 
-// 使用 deref_mut 宏实现 Deref 和 DerefMut 的结构体
+// 使用 deref!(mut ...) 宏实现 Deref 和 DerefMut 的结构体
 #[derive(Debug, Clone, Copy)]
 struct MacroDerefMut {
     value: i32,
 }
 
-deref_mut!(MacroDerefMut, i32, value);
+deref!(mut MacroDerefMut, i32, value);
 
 // 手动实现 Deref 和 DerefMut 的结构体
 #[derive(Debug, Clone, Copy)]
@@ -1,4 +1,4 @@
-use deref::{DerefMut, deref_mut};
+use deref::{DerefMut, deref};
 
 #[derive(Debug, DerefMut)]
 struct Hello<T> {
@@ -11,7 +11,7 @@ struct Hello2<T: Send> {
     inner: Hello<T>,
 }
 
-deref_mut!(<T: Send>, Hello2<T>, T, inner);
+deref!(mut <T: Send>, Hello2<T>, T, inner);
 
 fn main() {
     let mut hello = Hello {
@@ -1,3 +1,34 @@
+//! Declarative macros implementing `Deref` and `DerefMut`, along with the derive macros of
+//! `deref-derives` under the default `derive` feature.
+
 mod macros;
+pub mod wrapper;
 
+/// The derive macros, re-exported so a single dependency covers both kinds of macros
+///
+/// ```rust
+/// use deref::Deref;
+///
+/// #[derive(Deref)]
+/// struct Name(String);
+///
+/// let name = Name("deref".to_string());
+/// assert_eq!(name.len(), 5);
+/// ```
+///
+/// Without the feature (`default-features = false`), only the declarative macros are available.
+#[cfg(feature = "derive")]
 pub use deref_derives::*;
+
+/// Items used by the generated code, not part of the public API
+#[doc(hidden)]
+pub mod __private {
+    /// Implemented only when `Self` and `T` are the same type
+    #[diagnostic::on_unimplemented(
+        message = "the deref target `{T}` does not match the field type `{Self}`",
+        label = "field of type `{Self}`"
+    )]
+    pub trait SameType<T: ?Sized> {}
+
+    impl<T: ?Sized> SameType<T> for T {}
+}
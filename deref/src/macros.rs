@@ -4,6 +4,16 @@
 /// - `$ty`: The implementing type
 /// - `$target`: The target type
 /// - `$field`: Field access path, supports direct field names or index access
+/// - `where ...`: Optional where-clause after the field, emitted verbatim on the impl
+///   (higher-ranked `for<'a>` predicates included)
+///
+/// A leading `mut` implements `DerefMut` as well, with the same parameters.
+///
+/// Inline bounds may combine several traits and lifetimes, as in `<T: Send + Sync>`,
+/// `<'a, T: 'a>` or `<T: Clone + 'static>`. Default type parameters such as `<K, V = ()>` are
+/// accepted and left off the `impl` header. Generic parameters outside these forms, such as
+/// `const N: usize`, are emitted verbatim; bounds with generic arguments of their own, like
+/// `T: Into<u8>`, belong in the `where` clause there.
 ///
 /// # Examples
 /// ```rust
@@ -22,6 +32,36 @@
 /// ```rust
 /// use deref::deref;
 ///
+/// struct Counter {
+///     count: u32,
+/// }
+///
+/// // Implement both Deref and DerefMut
+/// deref!(mut Counter, u32, count);
+///
+/// let mut counter = Counter { count: 1 };
+/// *counter += 1;
+/// assert_eq!(*counter, 2);
+/// ```
+///
+/// ```rust
+/// use deref::deref;
+///
+/// struct SrVec<T> {
+///     vec: Vec<T>,
+/// }
+///
+/// // Generic parameters and where-clauses work the same with `mut`
+/// deref!(mut <T>, SrVec<T>, Vec<T>, vec where T: Clone);
+///
+/// let mut list = SrVec { vec: vec![1] };
+/// list.push(2);
+/// assert_eq!(list.len(), 2);
+/// ```
+///
+/// ```rust
+/// use deref::deref;
+///
 /// struct SrVec<T> {
 ///     vec: Vec<T>,
 /// }
@@ -33,6 +73,21 @@
 /// ```rust
 /// use deref::deref;
 ///
+/// struct Buf<const N: usize> {
+///     data: [u8; N],
+/// }
+///
+/// // Const generic parameters are emitted verbatim
+/// deref!(mut <const N: usize>, Buf<N>, [u8; N], data);
+///
+/// let mut buf = Buf { data: [0; 4] };
+/// buf[0] = 1;
+/// assert_eq!(buf.len(), 4);
+/// ```
+///
+/// ```rust
+/// use deref::deref;
+///
 /// struct MyType<'a> {
 ///     field: &'a usize,
 /// }
@@ -40,13 +95,138 @@
 /// // Implement Deref for a regular type
 /// deref!(<'a>, MyType<'a>, &'a usize, field);
 /// ```
+///
+/// ```rust
+/// use deref::deref;
+///
+/// struct Callback<F> {
+///     f: F,
+/// }
+///
+/// // Implement Deref with a higher-ranked where-clause
+/// deref!(<F>, Callback<F>, F, f where F: for<'a> Fn(&'a u8) -> bool);
+///
+/// let callback = Callback { f: |value: &u8| *value > 1 };
+/// assert!(callback(&2));
+/// assert!(!(*callback)(&1));
+/// ```
+///
+/// The anonymous lifetime `'_` can be used in the implementing type, where it is elided like in a
+/// hand-written `impl Deref for Name<'_>`. Field references coerce to the target, so a `&'a str`
+/// field can deref to `str`:
+///
+/// ```rust
+/// use deref::deref;
+///
+/// struct Name<'a> {
+///     value: &'a str,
+/// }
+///
+/// deref!(Name<'_>, str, value);
+///
+/// assert_eq!(Name { value: "deref" }.len(), 5);
+/// ```
+///
+/// Zero-sized targets such as `()` or unit structs work too, which is handy for marker wrappers
+/// that expose the methods of a marker type:
+///
+/// ```rust
+/// use deref::deref;
+///
+/// struct ReadOnly;
+///
+/// impl ReadOnly {
+///     fn can_write(&self) -> bool {
+///         false
+///     }
+/// }
+///
+/// struct Handle {
+///     mode: ReadOnly,
+///     unit: (),
+/// }
+///
+/// struct Unit {
+///     unit: (),
+/// }
+///
+/// deref!(Handle, ReadOnly, mode);
+/// deref!(Unit, (), unit);
+///
+/// let handle = Handle { mode: ReadOnly, unit: () };
+/// assert!(!handle.can_write());
+/// assert_eq!(*Unit { unit: handle.unit }, ());
+/// ```
+///
+/// The target is an associated type, where Rust does not allow `'_`; name the lifetime instead,
+/// e.g. `deref!(<'a>, Name<'a>, &'a str, value)`:
+///
+/// ```rust,compile_fail
+/// use deref::deref;
+///
+/// struct Name<'a> {
+///     value: &'a str,
+/// }
+///
+/// deref!(<'a>, Name<'a>, &'_ str, value);
+/// ```
 #[macro_export]
 macro_rules! deref {
+    (
+        mut
+        $(<
+            $( $($lt:lifetime),+ )?
+            $( , )?
+            $( $($param:ident $(: $bound:tt $(+ $extra:tt)*)? $(= $default:ty)?),+ )?
+        >,)?
+        $ty:ident
+        $(<
+            $( $($lt2:lifetime),+ )?
+            $( , )?
+            $( $($param2:ident),+ )?
+        >)?,
+        $target:ty,
+        $field:tt
+        $(where $($where:tt)+)?
+    ) => {
+        $crate::deref!(
+            $(<
+                $( $($lt),+, )?
+                $( $($param $(: $bound $(+ $extra)*)?),+ )?
+            >,)?
+            $ty
+            $(<
+                $( $($lt2),+, )?
+                $( $($param2),+ )?
+            >)?,
+            $target,
+            $field
+            $(where $($where)+)?
+        );
+
+        impl
+        $(<
+            $( $($lt),+, )?
+            $( $($param $(: $bound $(+ $extra)*)?),+ )?
+        >)?
+        ::core::ops::DerefMut for $ty
+        $(<
+            $( $($lt2),+, )?
+            $( $($param2),+ )?
+        >)?
+        $(where $($where)+)?
+        {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$field
+            }
+        }
+    };
     (
         $(<
             $( $($lt:lifetime),+ )?
             $( , )?
-            $( $($param:ident $(: $bound:tt)?),+ )?
+            $( $($param:ident $(: $bound:tt $(+ $extra:tt)*)? $(= $default:ty)?),+ )?
         >,)?
         $ty:ident
         $(<
@@ -56,17 +236,45 @@ macro_rules! deref {
         >)?,
         $target:ty,
         $field:tt
+        $(where $($where:tt)+)?
     ) => {
         impl
         $(<
             $( $($lt),+, )?
-            $( $($param $(: $bound)?),+ )?
+            $( $($param $(: $bound $(+ $extra)*)?),+ )?
         >)?
-        std::ops::Deref for $ty
+        ::core::ops::Deref for $ty
         $(<
             $( $($lt2),+, )?
             $( $($param2),+ )?
         >)?
+        $(where $($where)+)?
+        {
+            type Target = $target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.$field
+            }
+        }
+    };
+    // Generic parameters outside the grammar above, such as const generics, are split off at the
+    // closing `>` and emitted verbatim
+    (@verbatim $mut:tt [$($generics:tt)*] > , $($rest:tt)+) => {
+        $crate::deref!(@impl $mut [$($generics)*] $($rest)+);
+    };
+    (@verbatim $mut:tt [$($generics:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::deref!(@verbatim $mut [$($generics)* $next] $($rest)+);
+    };
+    (
+        @impl [] [$($generics:tt)*]
+        $ty:ident < $($arg:tt),+ >,
+        $target:ty,
+        $field:tt
+        $(where $($where:tt)+)?
+    ) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty<$($arg),+>
+        $(where $($where)+)?
         {
             type Target = $target;
 
@@ -76,102 +284,959 @@ macro_rules! deref {
             }
         }
     };
+    (
+        @impl [mut] [$($generics:tt)*]
+        $ty:ident < $($arg:tt),+ >,
+        $target:ty,
+        $field:tt
+        $(where $($where:tt)+)?
+    ) => {
+        $crate::deref!(@impl [] [$($generics)*] $ty<$($arg),+>, $target, $field $(where $($where)+)?);
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty<$($arg),+>
+        $(where $($where)+)?
+        {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$field
+            }
+        }
+    };
+    (mut < $($rest:tt)+) => {
+        $crate::deref!(@verbatim [mut] [] $($rest)+);
+    };
+    (< $($rest:tt)+) => {
+        $crate::deref!(@verbatim [] [] $($rest)+);
+    };
 }
 
 /// Macro to implement both Deref and DerefMut traits, supporting both regular types and generic types
 ///
-/// Note: This macro automatically implements both Deref and DerefMut traits.
-/// You don't need to separately use deref! when using deref_mut!.
+/// Deprecated alias of `deref!(mut ...)`, which takes the same parameters.
+#[macro_export]
+#[deprecated(note = "use `deref!(mut ...)` instead")]
+macro_rules! deref_mut {
+    ($($args:tt)*) => {
+        $crate::deref!(mut $($args)*);
+    };
+}
+
+/// Macro to implement the Deref trait together with `From<$target>` for a single-field struct
+///
+/// The generated `From` builds `Self { $field: value }`, which works for tuple structs (`0`) and
+/// named fields alike. Structs with more than one field fail to compile, since the other fields
+/// would be left uninitialized.
 ///
 /// # Parameters
 /// - `$ty`: The implementing type
-/// - `$target`: The target type
-/// - `$field`: Field access path, supports direct field names or index access
+/// - `$target`: The field type, which is both the deref target and the `From` source
+/// - `$field`: The only field, supports direct field names or index access
 ///
 /// # Examples
 /// ```rust
-/// use deref::{deref_mut};
+/// use deref::deref_from;
 ///
-/// struct MyType {
-///     field: TargetType,
+/// struct Meters(f64);
+///
+/// deref_from!(Meters, f64, 0);
+///
+/// let meters: Meters = 2.5.into();
+/// assert_eq!(*meters, 2.5);
+/// ```
+///
+/// ```rust
+/// use deref::deref_from;
+///
+/// struct Username {
+///     name: String,
 /// }
 ///
-/// struct TargetType;
+/// deref_from!(Username, String, name);
+///
+/// let user = Username::from("ferris".to_string());
+/// assert!(user.starts_with("fer"));
+/// ```
+///
+/// ```rust,compile_fail
+/// use deref::deref_from;
 ///
-/// // Implement Deref and DerefMut for a regular type
-/// // Note: This automatically implements both Deref and DerefMut
-/// deref_mut!(MyType, TargetType, field);
+/// struct Pair(u8, u8);
+///
+/// deref_from!(Pair, u8, 0);
 /// ```
+#[macro_export]
+macro_rules! deref_from {
+    ($ty:ident, $target:ty, $field:tt) => {
+        $crate::deref!($ty, $target, $field);
+
+        impl ::core::convert::From<$target> for $ty {
+            #[inline]
+            fn from(value: $target) -> Self {
+                Self { $field: value }
+            }
+        }
+    };
+}
+
+/// Macro to implement the Deref trait and check at compile time that the target is the field type
+///
+/// `deref!` accepts any target the field reference coerces to, so `deref!(Name, str, value)`
+/// also compiles for a `String` field. `deref_checked!` additionally asserts that `$target` is
+/// exactly the type of `$field`, turning an accidental mismatch into a compile error.
 ///
+/// # Parameters
+/// - `$ty`: The implementing type
+/// - `$target`: The target type, which must be the field type
+/// - `$field`: Field access path, supports direct field names or index access
+///
+/// # Examples
 /// ```rust
-/// use deref::{deref_mut};
+/// use deref::deref_checked;
 ///
-/// struct SrVec<T> {
-///     vec: Vec<T>,
+/// struct Name {
+///     value: String,
+/// }
+///
+/// deref_checked!(Name, String, value);
+///
+/// assert_eq!(Name { value: "checked".to_string() }.len(), 7);
+/// ```
+///
+/// ```rust,compile_fail
+/// use deref::deref_checked;
+///
+/// struct Name {
+///     value: String,
 /// }
 ///
-/// // Implement Deref and DerefMut for a single generic type
-/// // Note: This automatically implements both Deref and DerefMut
-/// deref_mut!(<T>, SrVec<T>, Vec<T>, vec);
+/// // `&String` would coerce to `&str`, but `str` is not the field type
+/// deref_checked!(Name, str, value);
 /// ```
+#[macro_export]
+macro_rules! deref_checked {
+    ($ty:ident, $target:ty, $field:tt) => {
+        $crate::deref!($ty, $target, $field);
+
+        const _: () = {
+            fn same_type<F: ?Sized + $crate::__private::SameType<T>, T: ?Sized>(_: &F) {}
+
+            #[allow(dead_code)]
+            fn check(value: &$ty) {
+                same_type::<_, $target>(&value.$field);
+            }
+        };
+    };
+}
+
+/// Macro to declare single-field tuple newtypes and implement Deref for each of them
 ///
+/// Every entry declares `struct $name($inner);` and implements `Deref<Target = $inner>` for it.
+/// Attributes (such as `#[derive(...)]`) and visibilities written on an entry are passed through
+/// to the generated struct.
+///
+/// # Parameters
+/// - `$name`: The newtype to declare
+/// - `$inner`: The wrapped type, which is also the deref target
+///
+/// # Examples
 /// ```rust
-/// use deref::{deref_mut};
+/// use deref::deref_tuple_newtypes;
 ///
-/// struct MyType<'a> {
-///     field: &'a mut usize,
+/// deref_tuple_newtypes! {
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub Celsius(pub f64),
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub Fahrenheit(pub f64),
+///     Kelvin(f64),
 /// }
 ///
-/// // Implement Deref and DerefMut for a regular type
-/// // Note: This automatically implements both Deref and DerefMut
-/// deref_mut!(<'a>, MyType<'a>, &'a mut usize, field);
+/// let celsius = Celsius(100.0);
+/// let fahrenheit = Fahrenheit(*celsius * 9.0 / 5.0 + 32.0);
+/// let kelvin = Kelvin(*celsius + 273.15);
+///
+/// assert_eq!(*fahrenheit, 212.0);
+/// assert_eq!(*kelvin, 373.15);
+/// assert!(kelvin.is_sign_positive());
 /// ```
 #[macro_export]
-macro_rules! deref_mut {
+macro_rules! deref_tuple_newtypes {
     (
-        $(<
-            $( $($lt:lifetime),+ )?
-            $( , )?
-            $( $($param:ident $(: $bound:tt)?),+ )?
-        >,)?
-        $ty:ident
-        $(<
-            $( $($lt2:lifetime),+ )?
-            $( , )?
-            $( $($param2:ident),+ )?
-        >)?,
-        $target:ty,
-        $field:tt
+        $(
+            $(#[$attr:meta])*
+            $vis:vis $name:ident ( $field_vis:vis $inner:ty )
+        ),* $(,)?
     ) => {
-        $crate::deref!(
-            $(<
-                $( $($lt),+, )?
-                $( $($param $(: $bound)?),+ )?
-            >,)?
-            $ty
-            $(<
-                $( $($lt2),+, )?
-                $( $($param2),+ )?
-            >)?,
-            $target,
-            $field
-        );
+        $(
+            $(#[$attr])*
+            $vis struct $name($field_vis $inner);
 
-        impl
-        $(<
-            $( $($lt),+, )?
-            $( $($param $(: $bound)?),+ )?
-        >)?
-        std::ops::DerefMut for $ty
-        $(<
-            $( $($lt2),+, )?
-            $( $($param2),+ )?
-        >)?
-        {
-            #[inline]
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.$field
-            }
-        }
+            $crate::deref!($name, $inner, 0);
+        )*
+    };
+}
+
+/// Macro to implement the Deref trait for different targets depending on `cfg` predicates
+///
+/// Every entry expands to a `deref!` invocation guarded by its `#[cfg(...)]`. The predicates of
+/// entries for the same type must be mutually exclusive, otherwise the active impls conflict.
+///
+/// # Parameters
+/// - `$cfg`: The configuration predicate enabling the entry
+/// - `$ty`: The implementing type
+/// - `$target`: The target type
+/// - `$field`: Field access path, supports direct field names or index access
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_cfg;
+///
+/// struct Wrapper {
+///     fast: u64,
+///     portable: u32,
+/// }
+///
+/// deref_cfg! {
+///     #[cfg(feature = "fast")]
+///     Wrapper => u64 @ fast,
+///     #[cfg(not(feature = "fast"))]
+///     Wrapper => u32 @ portable,
+/// }
+///
+/// let wrapper = Wrapper { fast: 1, portable: 2 };
+///
+/// // The `fast` feature is not enabled here
+/// assert_eq!(*wrapper, 2u32);
+/// ```
+#[macro_export]
+macro_rules! deref_cfg {
+    () => {};
+    // Collect the target type tokens up to the `@` separating it from the field
+    (@target [$cfg:meta] $ty:ident [$($target:tt)+] @ $field:tt $(, $($rest:tt)*)?) => {
+        #[cfg($cfg)]
+        $crate::deref!($ty, $($target)+, $field);
+
+        $crate::deref_cfg!($($($rest)*)?);
+    };
+    (@target [$cfg:meta] $ty:ident [$($target:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::deref_cfg!(@target [$cfg] $ty [$($target)* $next] $($rest)*);
+    };
+    (#[cfg($cfg:meta)] $ty:ident => $($rest:tt)+) => {
+        $crate::deref_cfg!(@target [$cfg] $ty [] $($rest)+);
+    };
+}
+
+/// Macro to implement the Deref trait with an arbitrary place expression as the target reference
+///
+/// The expression must start with `&self` or `&*self`. The `self` written in the invocation
+/// becomes the receiver of the generated method, so it can be used anywhere in the expression.
+///
+/// # Parameters
+/// - `$ty`: The implementing type
+/// - `$target`: The target type
+/// - `$expr`: The reference returned by `deref`, e.g. `&self.a.b.c`
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_expr;
+///
+/// struct Config {
+///     server: Server,
+/// }
+///
+/// struct Server {
+///     ports: Vec<u16>,
+///     primary: usize,
+/// }
+///
+/// struct App {
+///     config: Box<Config>,
+/// }
+///
+/// deref_expr!(App, u16, &self.config.server.ports[self.config.server.primary]);
+///
+/// let app = App {
+///     config: Box::new(Config {
+///         server: Server { ports: vec![80, 443], primary: 1 },
+///     }),
+/// };
+/// assert_eq!(*app, 443);
+/// ```
+///
+/// ```rust
+/// use deref::deref_expr;
+///
+/// struct Handle {
+///     value: Box<str>,
+/// }
+///
+/// deref_expr!(Handle, str, &*self.value);
+///
+/// let handle = Handle { value: "handle".into() };
+/// assert!(handle.starts_with("han"));
+/// ```
+#[macro_export]
+macro_rules! deref_expr {
+    ($ty:ty, $target:ty, & * $this:ident $($rest:tt)*) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            #[inline]
+            fn deref(&$this) -> &Self::Target {
+                &*$this $($rest)*
+            }
+        }
+    };
+    ($ty:ty, $target:ty, & $this:ident $($rest:tt)*) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            #[inline]
+            fn deref(&$this) -> &Self::Target {
+                &$this $($rest)*
+            }
+        }
+    };
+}
+
+/// Macro to implement both Deref and DerefMut traits with an arbitrary place expression
+///
+/// Note: This macro automatically implements both Deref and DerefMut traits.
+/// The expression must start with `&mut self` or `&mut *self`; `deref` borrows the same place
+/// immutably.
+///
+/// # Parameters
+/// - `$ty`: The implementing type
+/// - `$target`: The target type
+/// - `$expr`: The mutable reference returned by `deref_mut`, e.g. `&mut self.a.b.c`
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_mut_expr;
+///
+/// struct Grid {
+///     rows: Vec<Vec<u8>>,
+///     cursor: (usize, usize),
+/// }
+///
+/// deref_mut_expr!(Grid, u8, &mut self.rows[self.cursor.0][self.cursor.1]);
+///
+/// let mut grid = Grid { rows: vec![vec![0, 0], vec![0, 0]], cursor: (1, 0) };
+/// *grid = 7;
+/// assert_eq!(*grid, 7);
+/// assert_eq!(grid.rows, [[0, 0], [7, 0]]);
+/// ```
+///
+/// ```rust
+/// use deref::deref_mut_expr;
+///
+/// struct Name {
+///     value: Box<String>,
+/// }
+///
+/// deref_mut_expr!(Name, String, &mut *self.value);
+///
+/// let mut name = Name { value: Box::new("deref".to_string()) };
+/// name.push_str("-rs");
+/// assert_eq!(name.as_str(), "deref-rs");
+/// ```
+#[macro_export]
+macro_rules! deref_mut_expr {
+    ($ty:ty, $target:ty, &mut * $this:ident $($rest:tt)*) => {
+        $crate::deref_expr!($ty, $target, &* $this $($rest)*);
+
+        impl ::core::ops::DerefMut for $ty {
+            #[inline]
+            fn deref_mut(&mut $this) -> &mut Self::Target {
+                &mut *$this $($rest)*
+            }
+        }
+    };
+    ($ty:ty, $target:ty, &mut $this:ident $($rest:tt)*) => {
+        $crate::deref_expr!($ty, $target, & $this $($rest)*);
+
+        impl ::core::ops::DerefMut for $ty {
+            #[inline]
+            fn deref_mut(&mut $this) -> &mut Self::Target {
+                &mut $this $($rest)*
+            }
+        }
+    };
+}
+
+/// Macro to implement the Deref trait by calling a getter method instead of reading a field
+///
+/// Useful when the value is only reachable through a method such as `fn inner(&self) -> &Inner`,
+/// for instance because the field is private to another module or the reference is computed.
+///
+/// # Parameters
+/// - `$ty`: The implementing type, with generics written like for [`deref!`]
+/// - `$target`: The target type
+/// - `$method()`: Method taking `&self` and returning `&$target`
+/// - `where ...`: Optional where-clause after the method, emitted verbatim on the impl
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_via;
+///
+/// mod store {
+///     pub struct Store {
+///         items: Vec<u32>,
+///     }
+///
+///     impl Store {
+///         pub fn new(items: Vec<u32>) -> Self {
+///             Store { items }
+///         }
+///
+///         pub fn items(&self) -> &[u32] {
+///             &self.items
+///         }
+///     }
+/// }
+///
+/// deref_via!(store::Store, [u32], items());
+///
+/// let store = store::Store::new(vec![1, 2, 3]);
+/// assert_eq!(store.len(), 3);
+/// ```
+#[macro_export]
+macro_rules! deref_via {
+    (
+        <
+            $( $($lt:lifetime),+ )?
+            $( , )?
+            $( $($param:ident $(: $bound:tt $(+ $extra:tt)*)?),+ )?
+        >,
+        $ty:ident
+        <
+            $( $($lt2:lifetime),+ )?
+            $( , )?
+            $( $($param2:ident),+ )?
+        >,
+        $target:ty,
+        $method:ident()
+        $(where $($where:tt)+)?
+    ) => {
+        impl<
+            $( $($lt),+, )?
+            $( $($param $(: $bound $(+ $extra)*)?),+ )?
+        >
+        ::core::ops::Deref for $ty<
+            $( $($lt2),+, )?
+            $( $($param2),+ )?
+        >
+        $(where $($where)+)?
+        {
+            type Target = $target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                self.$method()
+            }
+        }
+    };
+    ($ty:ty, $target:ty, $method:ident() $(where $($where:tt)+)?) => {
+        impl ::core::ops::Deref for $ty
+        $(where $($where)+)?
+        {
+            type Target = $target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                self.$method()
+            }
+        }
+    };
+}
+
+/// Macro to implement the Deref trait for a wrapper whose field is a `Box`, dereferencing to the boxed value
+///
+/// This is the declarative counterpart of the derive's `#[deref(through)]` on a `Box<T>` field: the
+/// target is `T` rather than `Box<T>`.
+///
+/// # Parameters
+/// - `$ty`: The implementing type
+/// - `$target`: The boxed type, which is also the deref target
+/// - `$field`: Field holding the `Box<$target>`, supports direct field names or index access
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_boxed;
+///
+/// struct Message {
+///     body: Box<String>,
+/// }
+///
+/// deref_boxed!(Message, String, body);
+///
+/// let message = Message { body: Box::new("boxed".to_string()) };
+/// assert_eq!(message.len(), 5);
+/// assert_eq!(message.to_uppercase(), "BOXED");
+/// ```
+#[macro_export]
+macro_rules! deref_boxed {
+    ($ty:ty, $target:ty, $field:tt) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &*self.$field
+            }
+        }
+    };
+}
+
+/// Macro to implement both Deref and DerefMut traits for a wrapper whose field is a `Box`
+///
+/// Note: This macro automatically implements both Deref and DerefMut traits.
+///
+/// # Parameters
+/// - `$ty`: The implementing type
+/// - `$target`: The boxed type, which is also the deref target
+/// - `$field`: Field holding the `Box<$target>`, supports direct field names or index access
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_boxed_mut;
+///
+/// struct Buffer(Box<String>);
+///
+/// deref_boxed_mut!(Buffer, String, 0);
+///
+/// let mut buffer = Buffer(Box::new("deref".to_string()));
+/// buffer.push_str("-rs");
+/// assert_eq!(buffer.as_str(), "deref-rs");
+/// ```
+#[macro_export]
+macro_rules! deref_boxed_mut {
+    ($ty:ty, $target:ty, $field:tt) => {
+        $crate::deref_boxed!($ty, $target, $field);
+
+        impl ::core::ops::DerefMut for $ty {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut *self.$field
+            }
+        }
+    };
+}
+
+/// Macro to implement the Deref trait for a wrapper whose field is an `Rc` or `Arc`, dereferencing to the shared value
+///
+/// Shared pointers only hand out shared references, so no DerefMut variant is provided; use
+/// `Rc::make_mut`/`Arc::make_mut` on the field for mutation. `deref_rc!` and `deref_arc!` are
+/// aliases that document the pointer kind at the call site.
+///
+/// # Parameters
+/// - `$ty`: The implementing type
+/// - `$target`: The shared type, which is also the deref target
+/// - `$field`: Field holding the `Rc<$target>` or `Arc<$target>`, supports direct field names or index access
+///
+/// # Examples
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use deref::deref_arc;
+///
+/// struct Config {
+///     name: String,
+///     workers: usize,
+/// }
+///
+/// struct Handle {
+///     config: Arc<Config>,
+/// }
+///
+/// deref_arc!(Handle, Config, config);
+///
+/// let config = Arc::new(Config { name: "app".to_string(), workers: 4 });
+/// let handle = Handle { config: Arc::clone(&config) };
+/// assert_eq!(handle.name, "app");
+/// assert_eq!(handle.workers, 4);
+/// ```
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use deref::{deref_rc, deref_shared};
+///
+/// struct Local(Rc<Vec<u8>>);
+/// struct Any(Rc<str>);
+///
+/// deref_rc!(Local, Vec<u8>, 0);
+/// deref_shared!(Any, str, 0);
+///
+/// assert_eq!(Local(Rc::new(vec![1, 2])).len(), 2);
+/// assert!(Any(Rc::from("shared")).starts_with("sh"));
+/// ```
+#[macro_export]
+macro_rules! deref_shared {
+    ($ty:ty, $target:ty, $field:tt) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &*self.$field
+            }
+        }
+    };
+}
+
+/// Macro to implement the Deref trait for a wrapper whose field is an `Rc`, see `deref_shared!`
+#[macro_export]
+macro_rules! deref_rc {
+    ($ty:ty, $target:ty, $field:tt) => {
+        $crate::deref_shared!($ty, $target, $field);
+    };
+}
+
+/// Macro to implement the Deref trait for a wrapper whose field is an `Arc`, see `deref_shared!`
+#[macro_export]
+macro_rules! deref_arc {
+    ($ty:ty, $target:ty, $field:tt) => {
+        $crate::deref_shared!($ty, $target, $field);
+    };
+}
+
+/// Macro to implement the Deref trait for a wrapper whose `Vec` or array field derefs to a slice
+///
+/// This is the declarative counterpart of the derive's `#[deref(as_slice)]`: the target is
+/// `[$elem]` rather than the container type.
+///
+/// # Parameters
+/// - `$ty`: The implementing type
+/// - `$elem`: The element type of the slice
+/// - `$field`: Field holding the `Vec<$elem>` or `[$elem; N]`, supports direct field names or index access
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_slice;
+///
+/// struct Buffer {
+///     data: Vec<u8>,
+/// }
+///
+/// deref_slice!(Buffer, u8, data);
+///
+/// let buffer = Buffer { data: vec![1, 2, 3] };
+/// assert_eq!(buffer[1], 2);
+/// assert_eq!(&buffer[1..], [2, 3]);
+/// ```
+///
+/// ```rust
+/// use deref::deref_slice;
+///
+/// struct Block([u32; 4]);
+///
+/// deref_slice!(Block, u32, 0);
+///
+/// let block = Block([4, 3, 2, 1]);
+/// assert_eq!(block.len(), 4);
+/// assert_eq!(block.iter().min(), Some(&1));
+/// ```
+#[macro_export]
+macro_rules! deref_slice {
+    ($ty:ty, $elem:ty, $field:tt) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = [$elem];
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.$field[..]
+            }
+        }
+    };
+}
+
+/// Macro to implement both Deref and DerefMut traits for a wrapper whose `Vec` or array field derefs to a slice
+///
+/// Note: This macro automatically implements both Deref and DerefMut traits.
+///
+/// # Parameters
+/// - `$ty`: The implementing type
+/// - `$elem`: The element type of the slice
+/// - `$field`: Field holding the `Vec<$elem>` or `[$elem; N]`, supports direct field names or index access
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_slice_mut;
+///
+/// struct Samples([i16; 3]);
+///
+/// deref_slice_mut!(Samples, i16, 0);
+///
+/// let mut samples = Samples([3, -1, 2]);
+/// samples.sort_unstable();
+/// samples[0] = 0;
+/// assert_eq!(samples.0, [0, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! deref_slice_mut {
+    ($ty:ty, $elem:ty, $field:tt) => {
+        $crate::deref_slice!($ty, $elem, $field);
+
+        impl ::core::ops::DerefMut for $ty {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$field[..]
+            }
+        }
+    };
+}
+
+/// Macro to implement the Deref trait for a wrapper whose target is a fixed slot of an array field
+///
+/// The generated `deref` returns `&self.$field[$index]`, for wrappers that always expose the same
+/// element of a fixed-size array.
+///
+/// # Parameters
+/// - `$ty`: The implementing type
+/// - `$target`: The element type of the array
+/// - `$field`: Field holding the `[$target; N]`, supports direct field names or index access
+/// - `$index`: Constant expression of type `usize`, such as a literal or an associated const
+///
+/// The index is evaluated in an inline `const` block, so it may refer to `Self` and its associated
+/// consts, as in `Self::LEN - 1`, while anything that is not a constant expression is rejected at
+/// compile time. The bound is not checked there, since the macro does not know the array length:
+/// an index past the end of the array panics when dereferencing.
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_index_const;
+///
+/// struct Registers {
+///     slots: [u32; 4],
+/// }
+///
+/// impl Registers {
+///     const ACCUMULATOR: usize = 2;
+/// }
+///
+/// deref_index_const!(Registers, u32, slots, Registers::ACCUMULATOR);
+///
+/// let registers = Registers { slots: [0, 0, 42, 0] };
+/// assert_eq!(*registers, 42);
+/// ```
+///
+/// ```rust
+/// use deref::deref_index_const;
+///
+/// struct Last([&'static str; 3]);
+///
+/// deref_index_const!(Last, &'static str, 0, 3 - 1);
+///
+/// let last = Last(["a", "b", "c"]);
+/// assert_eq!(*last, "c");
+/// ```
+///
+/// A value only known at runtime is not accepted as the index:
+///
+/// ```rust,compile_fail
+/// use deref::deref_index_const;
+///
+/// fn slot() -> usize {
+///     1
+/// }
+///
+/// struct Pair([u8; 2]);
+///
+/// deref_index_const!(Pair, u8, 0, slot());
+/// ```
+#[macro_export]
+macro_rules! deref_index_const {
+    ($ty:ty, $target:ty, $field:tt, $index:expr) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.$field[const {
+                    let index: usize = $index;
+                    index
+                }]
+            }
+        }
+    };
+}
+
+/// Macro to implement the Deref trait for a wrapper over tuples of every arity up to 12
+///
+/// Given a generic wrapper holding a tuple, this generates `impl Deref for $wrapper<(T0, ..., Tn)>`
+/// with `Target` set to the element at `$index`, for every arity from `$index + 1` up to 12,
+/// like the standard library's tuple impls.
+///
+/// # Parameters
+/// - `$wrapper`: The generic wrapper type, with a single type parameter holding the tuple
+/// - `$field`: Field of the wrapper holding the tuple, supports direct field names or index access
+/// - `$index`: Index of the tuple element to deref to, from `0` to `11`
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_tuple_impls;
+///
+/// struct Row<T>(T);
+///
+/// // Deref to the first column of rows of any width
+/// deref_tuple_impls!(Row, 0, 0);
+///
+/// let pair = Row(("id", 2));
+/// assert_eq!(*pair, "id");
+///
+/// let wide = Row((1u8, 'b', "c", 4.0, [5]));
+/// assert_eq!(*wide, 1u8);
+/// ```
+///
+/// ```rust
+/// use deref::deref_tuple_impls;
+///
+/// struct Record<T> {
+///     columns: T,
+/// }
+///
+/// // Deref to the second column, implemented for arities 2 through 12
+/// deref_tuple_impls!(Record, columns, 1);
+///
+/// let record = Record { columns: (1, "name".to_string(), 3, 4, 5) };
+/// assert_eq!(record.len(), 4);
+/// ```
+#[macro_export]
+macro_rules! deref_tuple_impls {
+    // Implement for the current arity, then move the next remaining element into the tuple
+    (@grow $wrapper:ident, $field:tt, $index:tt, [$($before:ident)*] $target:ident [$($taken:ident)*] [$next:ident $($rest:ident)*]) => {
+        $crate::deref_tuple_impls!(@impl $wrapper, $field, $index, [$($before)*] $target [$($taken)*]);
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, $index, [$($before)*] $target [$($taken)* $next] [$($rest)*]);
+    };
+    (@grow $wrapper:ident, $field:tt, $index:tt, [$($before:ident)*] $target:ident [$($taken:ident)*] []) => {
+        $crate::deref_tuple_impls!(@impl $wrapper, $field, $index, [$($before)*] $target [$($taken)*]);
+    };
+    (@impl $wrapper:ident, $field:tt, $index:tt, [$($before:ident)*] $target:ident [$($taken:ident)*]) => {
+        impl<$($before,)* $target, $($taken),*> ::core::ops::Deref for $wrapper<($($before,)* $target, $($taken,)*)> {
+            type Target = $target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.$field.$index
+            }
+        }
+    };
+    ($wrapper:ident, $field:tt, 0) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 0, [] T0 [] [T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 1) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 1, [T0] T1 [] [T2 T3 T4 T5 T6 T7 T8 T9 T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 2) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 2, [T0 T1] T2 [] [T3 T4 T5 T6 T7 T8 T9 T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 3) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 3, [T0 T1 T2] T3 [] [T4 T5 T6 T7 T8 T9 T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 4) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 4, [T0 T1 T2 T3] T4 [] [T5 T6 T7 T8 T9 T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 5) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 5, [T0 T1 T2 T3 T4] T5 [] [T6 T7 T8 T9 T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 6) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 6, [T0 T1 T2 T3 T4 T5] T6 [] [T7 T8 T9 T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 7) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 7, [T0 T1 T2 T3 T4 T5 T6] T7 [] [T8 T9 T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 8) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 8, [T0 T1 T2 T3 T4 T5 T6 T7] T8 [] [T9 T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 9) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 9, [T0 T1 T2 T3 T4 T5 T6 T7 T8] T9 [] [T10 T11]);
+    };
+    ($wrapper:ident, $field:tt, 10) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 10, [T0 T1 T2 T3 T4 T5 T6 T7 T8 T9] T10 [] [T11]);
+    };
+    ($wrapper:ident, $field:tt, 11) => {
+        $crate::deref_tuple_impls!(@grow $wrapper, $field, 11, [T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10] T11 [] []);
+    };
+}
+
+/// Macro to implement the Deref trait for several types sharing generics, target and field
+///
+/// Every listed type expands to a `deref!` invocation with the same generic parameters, so the
+/// parameters and their bounds accept the same forms as in `deref!`.
+///
+/// # Parameters
+/// - `<...>`: Optional generic parameters shared by every impl
+/// - `[$ty, ...]`: The implementing types
+/// - `$target`: The target type
+/// - `$field`: Field access path, supports direct field names or index access
+///
+/// # Examples
+/// ```rust
+/// use deref::deref_for_each;
+///
+/// struct Pending<T> {
+///     inner: T,
+/// }
+///
+/// struct Running<T> {
+///     inner: T,
+///     started: u64,
+/// }
+///
+/// struct Done<T> {
+///     inner: T,
+///     code: i32,
+/// }
+///
+/// deref_for_each! { <T: Clone> [Pending<T>, Running<T>, Done<T>] => T @ inner }
+///
+/// let pending = Pending { inner: "job".to_string() };
+/// let running = Running { inner: pending.clone(), started: 1 };
+/// let done = Done { inner: running.clone(), code: 0 };
+/// assert_eq!(done.len(), 3);
+/// ```
+///
+/// ```rust
+/// use deref::deref_for_each;
+///
+/// struct Celsius(f64);
+/// struct Fahrenheit(f64);
+///
+/// // Tuple fields are selected by index
+/// deref_for_each! { [Celsius, Fahrenheit] => f64 @ 0 }
+///
+/// assert_eq!(*Celsius(20.0) + *Fahrenheit(68.0), 88.0);
+/// ```
+#[macro_export]
+macro_rules! deref_for_each {
+    // Collect the target type tokens up to the `@` separating it from the field
+    (@target $generics:tt $types:tt [$($target:tt)+] @ $field:tt) => {
+        $crate::deref_for_each!(@each $generics [$($target)+] $field $types);
+    };
+    (@target $generics:tt $types:tt [$($target:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::deref_for_each!(@target $generics $types [$($target)* $next] $($rest)*);
+    };
+    // Implement for the first remaining type, then for the others
+    (@each [$($generics:tt)*] [$($target:tt)+] $field:tt [$ty:ident $(<$($arg:tt),+>)? $(, $($rest:tt)*)?]) => {
+        $crate::deref!($($generics)* $ty $(<$($arg),+>)?, $($target)+, $field);
+
+        $crate::deref_for_each!(@each [$($generics)*] [$($target)+] $field [$($($rest)*)?]);
+    };
+    (@each $generics:tt $target:tt $field:tt []) => {};
+    (
+        $(<
+            $( $($lt:lifetime),+ )?
+            $( , )?
+            $( $($param:ident $(: $bound:tt $(+ $extra:tt)*)?),+ )?
+        >)?
+        [$($types:tt)+] => $($rest:tt)+
+    ) => {
+        $crate::deref_for_each!(
+            @target
+            [$(< $( $($lt),+, )? $( $($param $(: $bound $(+ $extra)*)?),+ )? >,)?]
+            [$($types)+]
+            []
+            $($rest)+
+        );
     };
 }
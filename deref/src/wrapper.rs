@@ -0,0 +1,37 @@
+//! Ready-made wrapper types
+
+/// Zero-cost newtype dereferencing to the wrapped value
+///
+/// Useful where a `Deref`/`DerefMut` wrapper is needed but a dedicated struct would add nothing.
+///
+/// ```rust
+/// use deref::wrapper::Transparent;
+///
+/// assert_eq!(*Transparent(5) + 1, 6);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Transparent<T>(pub T);
+
+crate::deref!(mut <T>, Transparent<T>, T, 0);
+
+impl<T> Transparent<T> {
+    /// Wrap `value`
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Transparent(value)
+    }
+
+    /// Unwrap the inner value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Transparent<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Transparent(value)
+    }
+}
@@ -0,0 +1,50 @@
+//! The invocations used by `benches/basic_benchmark.rs`, checked without running criterion
+
+use deref::deref;
+
+#[derive(Debug, Clone, Copy)]
+struct MacroDeref {
+    value: i32,
+}
+
+deref!(MacroDeref, i32, value);
+
+#[derive(Debug, Clone, Copy)]
+struct MacroDerefMut {
+    value: i32,
+}
+
+deref!(mut MacroDerefMut, i32, value);
+
+#[derive(Debug, Clone, Copy)]
+struct Inner {
+    value: i32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct OuterMacro {
+    inner: Inner,
+}
+
+deref!(OuterMacro, Inner, inner);
+
+#[test]
+fn macro_deref() {
+    let macro_deref = MacroDeref { value: 42 };
+    assert_eq!(*macro_deref, 42);
+}
+
+#[test]
+fn macro_deref_mut() {
+    let mut macro_deref = MacroDerefMut { value: 42 };
+    *macro_deref += 1;
+    assert_eq!(macro_deref.value, 43);
+}
+
+#[test]
+fn nested_macro_deref() {
+    let macro_deref = OuterMacro {
+        inner: Inner { value: 42 },
+    };
+    assert_eq!(macro_deref.value, 42);
+}
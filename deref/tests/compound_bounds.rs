@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use std::thread;
+
+use deref::{deref, deref_for_each};
+
+struct Shared<T: Send + Sync> {
+    value: Arc<T>,
+}
+
+deref!(<T: Send + Sync>, Shared<T>, Arc<T>, value);
+
+struct Cached<T: Clone + 'static> {
+    value: T,
+}
+
+deref!(mut <T: Clone + 'static>, Cached<T>, T, value);
+
+struct Local<'a, T: 'a + ToString>(&'a T);
+
+deref!(<'a, T: 'a + ToString>, Local<'a, T>, &'a T, 0);
+
+struct Left<T>(T);
+struct Right<T>(T);
+
+deref_for_each! { <T: Copy + Default> [Left<T>, Right<T>] => T @ 0 }
+
+#[test]
+fn send_and_sync_bound() {
+    let shared = Shared { value: Arc::new(3) };
+    let value = Arc::clone(&shared);
+    assert_eq!(thread::spawn(move || *value).join().unwrap(), 3);
+}
+
+#[test]
+fn trait_and_lifetime_bound() {
+    let mut cached = Cached { value: vec![1] };
+    cached.push(2);
+    assert_eq!(cached.clone(), [1, 2]);
+
+    let number = 4;
+    assert_eq!(Local(&number).to_string(), "4");
+}
+
+#[test]
+fn compound_bound_shared_by_several_types() {
+    assert_eq!(*Left(1) + *Right(2), 3);
+}
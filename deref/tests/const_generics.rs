@@ -0,0 +1,42 @@
+use deref::deref;
+
+struct Buf<const N: usize> {
+    data: [u8; N],
+}
+
+deref!(<const N: usize>, Buf<N>, [u8; N], data);
+
+struct Ring<T, const N: usize> {
+    slots: [T; N],
+    head: usize,
+}
+
+deref!(mut <T, const N: usize>, Ring<T, N>, [T; N], slots);
+
+struct Window<'a, T, const N: usize>(&'a [T; N]);
+
+deref!(<'a, T, const N: usize>, Window<'a, T, N>, [T; N], 0 where T: Copy);
+
+#[test]
+fn deref_to_a_const_generic_array() {
+    let buf = Buf { data: [1, 2, 3] };
+    assert_eq!(buf.len(), 3);
+    assert_eq!(buf[2], 3);
+}
+
+#[test]
+fn deref_mut_with_type_and_const_parameters() {
+    let mut ring = Ring {
+        slots: ["a"; 2],
+        head: 0,
+    };
+    ring[1] = "b";
+    assert_eq!((*ring, ring.head), (["a", "b"], 0));
+}
+
+#[test]
+fn const_parameters_with_lifetimes_and_where_clause() {
+    let values = [1.5, 2.5];
+    let window = Window(&values);
+    assert_eq!(window.iter().sum::<f64>(), 4.0);
+}
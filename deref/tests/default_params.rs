@@ -0,0 +1,34 @@
+use std::collections::BTreeMap;
+
+use deref::deref;
+
+struct Map<K, V = ()> {
+    inner: BTreeMap<K, V>,
+}
+
+deref!(mut <K: Ord, V = ()>, Map<K, V>, BTreeMap<K, V>, inner);
+
+struct Pair<A, B: Default = u8>(A, B);
+
+deref!(<A, B: Default = u8>, Pair<A, B>, A, 0);
+
+#[test]
+fn default_parameter_used() {
+    let mut set: Map<&str> = Map { inner: BTreeMap::new() };
+    set.insert("a", ());
+    assert!(set.contains_key("a"));
+}
+
+#[test]
+fn default_parameter_overridden() {
+    let mut map: Map<&str, u32> = Map { inner: BTreeMap::new() };
+    map.insert("a", 1);
+    assert_eq!(map.get("a"), Some(&1));
+}
+
+#[test]
+fn bounded_default_parameter() {
+    let pair: Pair<&str> = Pair("left", 0);
+    assert_eq!(pair.len(), 4);
+    assert_eq!(pair.1, 0);
+}
@@ -0,0 +1,39 @@
+use deref::deref_cfg;
+
+struct Config {
+    test_value: &'static str,
+    release_value: u8,
+}
+
+deref_cfg! {
+    #[cfg(test)]
+    Config => &'static str @ test_value,
+    #[cfg(not(test))]
+    Config => u8 @ release_value,
+}
+
+struct Pair(std::collections::HashMap<u8, u16>, u32);
+
+deref_cfg! {
+    #[cfg(test)]
+    Pair => std::collections::HashMap<u8, u16> @ 0,
+    #[cfg(not(test))]
+    Pair => u32 @ 1,
+}
+
+#[test]
+fn only_active_cfg_is_implemented() {
+    let config = Config {
+        test_value: "test",
+        release_value: 1,
+    };
+    assert_eq!(*config, "test");
+    assert_eq!(config.release_value, 1);
+}
+
+#[test]
+fn targets_may_contain_commas() {
+    let pair = Pair([(1, 2)].into(), 3);
+    assert_eq!(pair.get(&1), Some(&2));
+    assert_eq!(pair.1, 3);
+}
@@ -0,0 +1,108 @@
+use deref::{deref, deref_impl};
+
+struct Inner {
+    value: u8,
+}
+
+struct ByMacro<'a, T> {
+    items: &'a [T],
+}
+
+struct ByImpl<'a, T> {
+    items: &'a [T],
+}
+
+struct ByInference<'a, T> {
+    items: &'a [T],
+}
+
+deref!(<'a, T>, ByMacro<'a, T>, &'a [T], items);
+deref_impl!(impl<'a, T> ByImpl<'a, T>, &'a [T], items);
+deref_impl!(ByInference<'a, T>, &'a [T], items);
+
+struct Concrete(Vec<u8>, Inner);
+
+deref_impl!(Concrete, Vec<u8>, 0);
+
+struct Pinned<T>(u8, T);
+
+deref_impl!(Pinned<u8>, u8, 0);
+deref_impl!(Pinned<crate::Inner>, Inner, 1);
+
+struct Bounded<T> {
+    value: T,
+}
+
+deref_impl!(Bounded<T>, T, value where T: Clone);
+
+struct Pair<A, B>(A, B);
+
+deref_impl!(Pair<K, K>, K, 1);
+
+struct Named<T>(T);
+
+deref_impl!(Named<String>, String, 0);
+
+struct Label<'a> {
+    text: &'a str,
+}
+
+deref_impl!(Label<'a>, str, text);
+
+struct Array<T: Copy, const N: usize>([T; N]);
+
+deref_impl!(impl<T: Copy, const N: usize> Array<T, N>, [T; N], 0);
+
+#[test]
+fn matches_deref_with_explicit_generics() {
+    let items = [1, 2, 3];
+    let by_macro = ByMacro { items: &items };
+    let by_impl = ByImpl { items: &items };
+
+    assert_eq!(*by_macro, *by_impl);
+    assert_eq!(by_macro.len(), by_impl.len());
+}
+
+#[test]
+fn inferred_type_parameters_are_generic() {
+    let numbers = ByInference { items: &[1u8, 2] };
+    let words = ByInference {
+        items: &["a", "b", "c"],
+    };
+
+    assert_eq!(numbers.len(), 2);
+    assert_eq!(words[2], "c");
+    assert_eq!(*Pair(1u8, 2u8), 2);
+    assert_eq!(*Pair("first", "second"), "second");
+}
+
+#[test]
+fn concrete_and_path_arguments() {
+    let concrete = Concrete(vec![1], Inner { value: 2 });
+    assert_eq!(concrete.len(), 1);
+    assert_eq!(concrete.1.value, 2);
+
+    assert_eq!(*Pinned(1, 2u8), 1);
+    assert_eq!(Pinned(1, Inner { value: 3 }).value, 3);
+}
+
+#[test]
+fn concrete_non_primitive_argument() {
+    let named = Named(String::from("name"));
+    assert_eq!(named.len(), 4);
+    assert_eq!(named.to_uppercase(), "NAME");
+}
+
+#[test]
+fn inferred_lifetime_and_explicit_generics() {
+    assert_eq!(Label { text: "label" }.len(), 5);
+    assert_eq!(Array([1u8, 2]).len(), 2);
+}
+
+#[test]
+fn where_clause_after_field() {
+    let bounded = Bounded {
+        value: String::from("clone"),
+    };
+    assert_eq!(bounded.clone(), "clone");
+}
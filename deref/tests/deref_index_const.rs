@@ -0,0 +1,26 @@
+use deref::deref_index_const;
+
+struct Window {
+    slots: [u8; 3],
+}
+
+impl Window {
+    const LEN: usize = 3;
+}
+
+deref_index_const!(Window, u8, slots, Self::LEN - 1);
+
+struct First([&'static str; 2]);
+
+deref_index_const!(First, &'static str, 0, 0);
+
+#[test]
+fn index_from_an_associated_const_of_self() {
+    let window = Window { slots: [1, 2, 3] };
+    assert_eq!(*window, 3);
+}
+
+#[test]
+fn literal_index() {
+    assert_eq!(*First(["a", "b"]), "a");
+}
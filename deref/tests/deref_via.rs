@@ -0,0 +1,67 @@
+use std::fmt::Display;
+
+use deref::deref_via;
+
+mod private {
+    pub struct Secret {
+        value: String,
+    }
+
+    impl Secret {
+        pub fn new(value: &str) -> Self {
+            Secret { value: value.into() }
+        }
+
+        pub fn value(&self) -> &str {
+            &self.value
+        }
+    }
+}
+
+deref_via!(private::Secret, str, value());
+
+struct Cache<T> {
+    entries: Vec<T>,
+}
+
+impl<T> Cache<T> {
+    fn latest(&self) -> &T {
+        self.entries.last().expect("cache is never empty")
+    }
+}
+
+deref_via!(<T>, Cache<T>, T, latest());
+
+struct Labels<'a, T: Display> {
+    labels: &'a [T],
+}
+
+impl<'a, T: Display> Labels<'a, T> {
+    fn first(&self) -> &T {
+        &self.labels[0]
+    }
+}
+
+deref_via!(<'a, T: Display>, Labels<'a, T>, T, first());
+
+#[test]
+fn private_field_through_getter() {
+    let secret = private::Secret::new("hidden");
+
+    assert_eq!(&*secret, "hidden");
+    assert!(secret.starts_with("hid"));
+}
+
+#[test]
+fn generic_computed_target() {
+    let cache = Cache { entries: vec![1, 2, 3] };
+
+    assert_eq!(*cache, 3);
+}
+
+#[test]
+fn generic_with_lifetime_and_bound() {
+    let labels = Labels { labels: &["a", "b"] };
+
+    assert_eq!(labels.to_string(), "a");
+}
@@ -0,0 +1,44 @@
+use deref::deref;
+
+struct Ref<'a, T: 'a> {
+    r: &'a T,
+}
+
+deref!(<'a, T: 'a>, Ref<'a, T>, &'a T, r);
+
+struct Cloned<'a, T: 'a + Clone> {
+    r: &'a T,
+}
+
+deref!(<'a, T: 'a + Clone>, Cloned<'a, T>, &'a T, r);
+
+struct Slot<'a, T: 'a + Default> {
+    value: &'a mut T,
+}
+
+deref!(mut <'a, T: 'a + Default>, Slot<'a, T>, &'a mut T, value);
+
+#[test]
+fn lifetime_bound_alone() {
+    let value = String::from("ref");
+    let wrapper = Ref { r: &value };
+
+    assert_eq!(wrapper.len(), 3);
+}
+
+#[test]
+fn lifetime_and_trait_bound() {
+    let value = vec![1, 2];
+    let wrapper = Cloned { r: &value };
+
+    assert_eq!((*wrapper).clone(), [1, 2]);
+}
+
+#[test]
+fn lifetime_bound_with_deref_mut() {
+    let mut value = 1;
+    let mut slot = Slot { value: &mut value };
+    **slot = u32::default();
+
+    assert_eq!(value, 0);
+}
@@ -0,0 +1,53 @@
+use deref::deref;
+
+struct Meters(f64);
+
+deref!(mut Meters, f64, 0);
+
+struct Cursor<'a, T> {
+    items: &'a mut [T],
+}
+
+deref!(mut <'a, T>, Cursor<'a, T>, &'a mut [T], items);
+
+struct Only {
+    value: u8,
+}
+
+deref!(Only, u8, value);
+
+#[allow(deprecated)]
+mod legacy {
+    pub struct Legacy {
+        pub value: String,
+    }
+
+    deref::deref_mut!(Legacy, String, value);
+}
+
+use legacy::Legacy;
+
+#[test]
+fn mut_form_implements_deref_mut() {
+    let mut meters = Meters(1.0);
+    *meters += 0.5;
+    assert_eq!(*meters, 1.5);
+
+    let mut items = [3, 1, 2];
+    let mut cursor = Cursor { items: &mut items };
+    cursor.sort();
+    assert_eq!(cursor.items, [1, 2, 3]);
+}
+
+#[test]
+fn plain_form_only_implements_deref() {
+    let only = Only { value: 4 };
+    assert_eq!(*only, 4);
+}
+
+#[test]
+fn deprecated_alias_still_works() {
+    let mut legacy = Legacy { value: "a".to_string() };
+    legacy.push('b');
+    assert_eq!(legacy.as_str(), "ab");
+}
@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+// Local items named like the standard crates must not capture generated paths
+mod std {}
+mod core {}
+
+use deref::{Deref, deref, deref_boxed_mut, deref_from, deref_impl, deref_shared, deref_slice_mut};
+
+#[derive(Deref)]
+struct Derived(u8);
+
+struct Plain {
+    value: u8,
+}
+
+deref!(Plain, u8, value);
+
+struct Generic<T> {
+    value: T,
+}
+
+deref!(mut <T>, Generic<T>, T, value);
+
+struct Converted(u8);
+
+deref_from!(Converted, u8, 0);
+
+struct Boxed(::std::boxed::Box<u8>);
+
+deref_boxed_mut!(Boxed, u8, 0);
+
+struct Shared(::std::rc::Rc<u8>);
+
+deref_shared!(Shared, u8, 0);
+
+struct Slice([u8; 2]);
+
+deref_slice_mut!(Slice, u8, 0);
+
+struct Inferred<T>(T);
+
+deref_impl!(Inferred<T>, T, 0);
+
+#[test]
+fn macros_ignore_local_shadowing() {
+    assert_eq!(*Derived(1), 1);
+    assert_eq!(*Plain { value: 2 }, 2);
+    assert_eq!(*Generic { value: 3 }, 3);
+    assert_eq!(*Converted::from(4), 4);
+    assert_eq!(*Boxed(::std::boxed::Box::new(5)), 5);
+    assert_eq!(*Shared(::std::rc::Rc::new(6)), 6);
+    assert_eq!(Slice([7, 8]).len(), 2);
+    assert_eq!(*Inferred(9), 9);
+}
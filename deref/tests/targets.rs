@@ -0,0 +1,77 @@
+use deref::deref;
+
+struct Inner<T = u8> {
+    value: T,
+}
+
+struct Outer {
+    inner: Inner,
+}
+
+deref!(Outer, Inner, inner);
+
+#[test]
+fn defaulted_type_param_target() {
+    let outer = Outer {
+        inner: Inner { value: 3 },
+    };
+
+    let inner: &Inner<u8> = &outer;
+    assert_eq!(inner.value, 3u8);
+}
+
+type MyRef<'a> = &'a Inner<u16>;
+
+struct Wrapper<'a> {
+    field: MyRef<'a>,
+}
+
+deref!(<'a>, Wrapper<'a>, MyRef<'a>, field);
+
+#[test]
+fn parameterized_type_alias_target() {
+    let inner = Inner { value: 5u16 };
+    let wrapper = Wrapper { field: &inner };
+
+    assert_eq!(wrapper.value, 5);
+}
+
+struct Registry {
+    entries: std::collections::HashMap<String, Vec<std::sync::Arc<std::sync::Mutex<Inner<u32>>>>>,
+}
+
+deref!(
+    Registry,
+    std::collections::HashMap<
+        String,
+        Vec<std::sync::Arc<std::sync::Mutex<Inner<u32>>>>,
+    >,
+    entries
+);
+
+#[test]
+fn deeply_nested_target() {
+    let inner = std::sync::Arc::new(std::sync::Mutex::new(Inner { value: 5u32 }));
+    let registry = Registry {
+        entries: std::collections::HashMap::from([("a".to_string(), vec![inner])]),
+    };
+
+    assert_eq!(registry["a"][0].lock().unwrap().value, 5);
+}
+
+fn first(bytes: &u8) -> &u8 {
+    bytes
+}
+
+struct Callback {
+    func: for<'a> fn(&'a u8) -> &'a u8,
+}
+
+deref!(Callback, for<'a> fn(&'a u8) -> &'a u8, func);
+
+#[test]
+fn higher_ranked_fn_pointer_target() {
+    let callback = Callback { func: first };
+
+    assert_eq!(*(*callback)(&7), 7);
+}
@@ -0,0 +1,26 @@
+use deref::wrapper::Transparent;
+
+#[test]
+fn deref_to_inner() {
+    let name = Transparent::new(String::from("deref"));
+
+    assert_eq!(name.len(), 5);
+    assert_eq!(&*name, "deref");
+}
+
+#[test]
+fn deref_mut_to_inner() {
+    let mut list = Transparent(vec![1, 2]);
+    list.push(3);
+    *list = list.iter().map(|value| value * 2).collect();
+
+    assert_eq!(*list, [2, 4, 6]);
+}
+
+#[test]
+fn into_inner_and_from() {
+    let wrapped: Transparent<&str> = "value".into();
+
+    assert_eq!(wrapped, Transparent("value"));
+    assert_eq!(wrapped.into_inner(), "value");
+}
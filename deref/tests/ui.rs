@@ -0,0 +1,8 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/deref_checked_*.rs");
+    // `deref_impl!` comes from the derive crate
+    #[cfg(feature = "derive")]
+    t.compile_fail("tests/ui/deref_impl_*.rs");
+}
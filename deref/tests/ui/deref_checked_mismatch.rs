@@ -0,0 +1,9 @@
+use deref::deref_checked;
+
+struct Name {
+    value: String,
+}
+
+deref_checked!(Name, str, value);
+
+fn main() {}
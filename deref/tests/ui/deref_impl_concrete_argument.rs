@@ -0,0 +1,9 @@
+use deref::deref_impl;
+
+struct Named<T>(T);
+
+deref_impl!(Named<String>, String, 0);
+
+fn main() {
+    let _ = *Named(5u8);
+}
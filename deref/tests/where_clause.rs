@@ -0,0 +1,46 @@
+use deref::deref;
+
+struct Peek<I: Iterator> {
+    iter: I,
+}
+
+deref!(<I: Iterator>, Peek<I>, I, iter where I::Item: Clone);
+
+struct Pair<T, U> {
+    left: T,
+    right: U,
+}
+
+deref!(mut <T, U>, Pair<T, U>, T, left where T: Clone + PartialEq, U: Default,);
+
+struct Borrowed<'a, T> {
+    value: &'a T,
+}
+
+deref!(<'a, T>, Borrowed<'a, T>, &'a T, value where T: 'a + ToString);
+
+#[test]
+fn single_predicate_on_an_associated_type() {
+    let mut peek = Peek {
+        iter: vec!["a".to_string()].into_iter(),
+    };
+    assert_eq!(peek.len(), 1);
+    assert_eq!(peek.iter.next().as_deref(), Some("a"));
+}
+
+#[test]
+fn multi_predicate_clause_with_deref_mut() {
+    let mut pair = Pair {
+        left: 1,
+        right: String::new(),
+    };
+    *pair += 1;
+    assert_eq!((*pair, pair.right.as_str()), (2, ""));
+}
+
+#[test]
+fn lifetime_predicate() {
+    let value = 5;
+    let borrowed = Borrowed { value: &value };
+    assert_eq!(borrowed.to_string(), "5");
+}